@@ -30,11 +30,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let mut stream = MenuStream::default();
     writeln!(stream, "Describe your project")?;
 
-    let mut lic = Values::from(&mut stream).format(Format {
-        prefix: "==> ",
-        chip: " = ",
-        ..Default::default()
-    });
+    let mut lic = Values::from(&mut stream).format(Format::prefix("==> ").with_chip(" = "));
 
     let authors: Vec<String> =
         lic.many_written(&Written::from("Authors").example("Ahmad, ..."), ", ")?;
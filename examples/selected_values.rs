@@ -3,12 +3,11 @@ use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
     let amount = Selected::new("how many", [("zero", 0), ("one", 1), ("two", 2)])
-        .format(Format {
-            suffix: "> ",
-            line_brk: false,
-            show_default: false,
-            ..Default::default()
-        })
+        .format(
+            Format::suffix("> ")
+                .with_line_brk(false)
+                .with_show_default(false),
+        )
         .default(1)
         .select(&mut MenuStream::default())?;
 
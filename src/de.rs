@@ -0,0 +1,195 @@
+//! Module used to deserialize the answers of a form into a user type,
+//! with the [serde](https://docs.rs/serde/) crate, or to collect them
+//! into a JSON value with the [serde_json](https://docs.rs/serde_json/) crate.
+
+use crate::utils::parse_bool;
+use crate::{MenuError, MenuResult};
+use serde::de::value::{Error, MapDeserializer};
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Unexpected, Visitor};
+use serde::forward_to_deserialize_any;
+
+/// Deserializer of a single answer, parsing it according to the type expected
+/// by the visitor.
+struct Value<'de>(&'de str);
+
+/// Implements the deserializing methods of the types parsed with their `FromStr` implementation.
+macro_rules! deserialize_parsed {
+    ($($f:ident => $visit:ident,)*) => {$(
+        fn $f<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            match self.0.trim().parse() {
+                Ok(v) => visitor.$visit(v),
+                Err(_) => Err(de::Error::invalid_value(Unexpected::Str(self.0), &visitor)),
+            }
+        }
+    )*};
+}
+
+impl<'de> de::Deserializer<'de> for Value<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_borrowed_str(self.0)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match parse_bool(self.0) {
+            Some(b) => visitor.visit_bool(b),
+            None => Err(de::Error::invalid_value(Unexpected::Str(self.0), &visitor)),
+        }
+    }
+
+    deserialize_parsed!(
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    );
+
+    /// An empty answer is deserialized as `None`.
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0.is_empty() {
+            true => visitor.visit_none(),
+            false => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// The answer is deserialized as the name of a unit variant.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 str string bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for Value<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+/// Deserializes the given answers into the output type, using the key of each answer
+/// as the name of the corresponding struct field.
+///
+/// The answers are parsed according to the type of each struct field, and an empty answer
+/// is deserialized as `None` for an `Option` field. The unit variants of an enum are
+/// deserialized from their name. The keys that do not correspond to any field are ignored,
+/// unless the type denies them.
+///
+/// It returns a [`MenuError::Other`] error describing the failure,
+/// for instance if an answer is missing or incorrect.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use ezmenulib::menu::from_map;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     name: String,
+///     port: u16,
+///     verbose: bool,
+/// }
+///
+/// let answers = HashMap::from([
+///     ("name", "server"),
+///     ("port", "8080"),
+///     ("verbose", "yes"),
+/// ]);
+/// let config: Config = from_map(&answers).unwrap();
+/// assert_eq!(config.port, 8080);
+/// assert!(config.verbose);
+/// ```
+pub fn from_map<T, I, K, V>(map: I) -> MenuResult<T>
+where
+    T: DeserializeOwned,
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let answers: Vec<(K, V)> = map.into_iter().collect();
+    let de: MapDeserializer<'_, _, Error> = MapDeserializer::new(
+        answers
+            .iter()
+            .map(|(k, v)| (Value(k.as_ref()), Value(v.as_ref()))),
+    );
+    T::deserialize(de).map_err(|e| MenuError::from(e.to_string()))
+}
+
+/// Defines the type of the JSON value produced by the answer of a written field
+/// (see [`Values::written_json`](crate::menu::Values::written_json)).
+///
+/// The answer is parsed as the corresponding Rust type, so the field is prompted again
+/// if the answer is incorrect, and it is never guessed from the text of the answer:
+/// an answer such as `"007"` remains a string unless the field is declared as a number.
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonType {
+    /// A JSON string, with the answer as it is.
+    String,
+    /// A JSON number, with the answer parsed as an `i64`.
+    Integer,
+    /// A JSON number, with the answer parsed as a finite `f64`.
+    Float,
+    /// A JSON boolean, with the answer parsed as a `bool`, for instance
+    /// with a [boolean](crate::field::Written::boolean) field.
+    Bool,
+}
+
+/// Inserts the given value into the JSON object, at the path given by the dotted key,
+/// for instance `"server.port"`, creating the nested objects if needed.
+///
+/// It returns a [`MenuError::Other`] error if the key is already used, or if the path
+/// goes through a value that is not an object, for instance with the `"server"`
+/// and `"server.port"` keys.
+#[cfg(feature = "json")]
+pub(crate) fn insert_dotted(
+    root: &mut serde_json::Map<String, serde_json::Value>,
+    key: &str,
+    value: serde_json::Value,
+) -> MenuResult {
+    let mut parts: Vec<&str> = key.split('.').collect();
+    let last = parts.pop().unwrap_or_default();
+    let conflict = || format!("the key `{}` conflicts with another key", key);
+    let mut obj = root;
+    for part in parts {
+        obj = obj
+            .entry(part)
+            .or_insert_with(|| serde_json::Value::Object(Default::default()))
+            .as_object_mut()
+            .ok_or_else(conflict)?;
+    }
+    match obj.contains_key(last) {
+        true => Err(MenuError::from(conflict())),
+        false => {
+            obj.insert(last.to_owned(), value);
+            Ok(())
+        }
+    }
+}
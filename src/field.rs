@@ -6,17 +6,24 @@ mod tests;
 use crate::prelude::*;
 use crate::utils::*;
 use crate::DEFAULT_FMT;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::env;
 use std::fmt::{self, Display, Formatter};
+#[cfg(feature = "async")]
+use std::future::Future;
 use std::io::{BufRead, Write};
+use std::ops::RangeInclusive;
 use std::str::FromStr;
+use std::time::Duration;
 
 /// Builds the associated functions of the [`Format`] struct
 /// according to its fields.
 macro_rules! impl_fmt {
     ($(#[doc = $main_doc:expr])*
     $(
-        $i:ident: $t:ty,
+        $i:ident / $set:ident: $t:ty,
         $(#[doc = $doc:expr])*
     )*) => {
         $(#[doc = $main_doc])*
@@ -33,7 +40,21 @@ macro_rules! impl_fmt {
             /// If it a specification corresponds to the default specification
             /// (see [`Format::default`]), for instance `prefix`, it will be replaced
             /// by the `r` specification of `prefix`.
-            pub(crate) fn merged(&self, r: &Format<'a>) -> Self {
+            ///
+            /// It is the format actually used by a field prompted by a container,
+            /// so it is useful to inspect why a field is rendered a certain way.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use ezmenulib::field::Format;
+            /// let fmt = Format::suffix("> ").merged(&Format::prefix("==> "));
+            /// assert_eq!(fmt.suffix, "> ");
+            /// assert_eq!(fmt.prefix, "==> ");
+            /// ```
+            // The function pointers are only compared to the default `None` value.
+            #[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
+            pub fn merged(&self, r: &Format<'a>) -> Self {
                 Self {$(
                     $i: if self.$i == DEFAULT_FMT.$i { r.$i } else { self.$i },
                 )*}
@@ -49,6 +70,16 @@ macro_rules! impl_fmt {
                 }
             }
             )*
+
+            // Setters
+            $(
+            #[doc = concat!("Sets the `", stringify!($i), "` field of the format, ")]
+            #[doc = concat!("as a chainable version of [`Format::", stringify!($i), "`].")]
+            pub fn $set(mut self, $i: $t) -> Self {
+                self.$i = $i;
+                self
+            }
+            )*
         }
     }
 }
@@ -72,32 +103,47 @@ impl_fmt!(
     /// - `<...>` means a given string slice.
     /// - `{...}` means that the value inside is chose to be displayed or not (boolean).
     /// - `[...]` means that the value inside is displayed if it is available.
-    prefix: &'a str,
+    prefix / with_prefix: &'a str,
     /// Sets the prefix of the formatting (`"--> "` by default).
     ///
     /// It corresponds to the string slice displayed at the beginning of the field message.
-    left_sur: &'a str,
+    left_sur / with_left_sur: &'a str,
     /// Defines the left "surrounding" of the index when displaying a list ("[" by default).
     ///
     /// It is displayed between at the beginning of the list field line, before the index.
-    right_sur: &'a str,
+    right_sur / with_right_sur: &'a str,
     /// Defines the right "surrounding" of the index when displaying a list ("]" by default).
     ///
     /// It is displayed between the index and the chip.
-    chip: &'a str,
+    chip / with_chip: &'a str,
     /// Defines the chip as marker type for lists (`" - "` by default).
     ///
     /// It is displayed between the index and the field message among the selectable fields.
-    show_default: bool,
-    /// Defines if it displays the default value or not (`true` by default).
+    show_default / with_show_default: bool,
+    /// Defines if it displays the default value of a written field or not (`true` by default).
     ///
     /// If an example is provided in the current written field,
     /// the latter will always be displayed.
-    suffix: &'a str,
+    ///
+    /// For selectable fields, see the `mark_default` format specification.
+    mark_default / with_mark_default: bool,
+    /// Defines if it marks the default field of a selectable list with `"(default)"`
+    /// or not (`true` by default).
+    ///
+    /// It is independent of the `show_default` specification, so the default value
+    /// can be shown on written fields without being marked in the selectable lists,
+    /// or vice versa.
+    mark_optional / with_mark_optional: bool,
+    /// Defines if it marks the optional fields with `"optional"` or not (`true` by default).
+    ///
+    /// A field is optional if it is prompted with the optional methods, such as
+    /// [`Written::optional_value`] or [`Selected::optional_select`], and if it has
+    /// no default value, so the user knows the field can be left blank.
+    suffix / with_suffix: &'a str,
     /// Sets the prefix of the formatting (`">> "` by default).
     ///
     /// It is displayed right before the user input, on the same line.
-    line_brk: bool,
+    line_brk / with_line_brk: bool,
     /// Defines if it breaks the line right before the suffix (`true` by default).
     ///
     /// If it does, re-prompting the field will not display the message again,
@@ -106,8 +152,268 @@ impl_fmt!(
     ///
     /// For selectable fields, if `new_line` format specification is set as `false`,
     /// it will use the default suffix, and always use a line break, for more convenience.
+    match_mode / with_match_mode: MatchMode,
+    /// Defines how the user input is matched against the selectable fields
+    /// (`MatchMode::IndexFirst` by default).
+    ///
+    /// See [`MatchMode`] for more information.
+    invalid_msg / with_invalid_msg: &'a str,
+    /// Defines the message displayed when the user input is incorrect,
+    /// right before prompting the field again (empty by default).
+    ///
+    /// If it is empty, the field is prompted again without any message.
+    echo_newline / with_echo_newline: bool,
+    /// Defines if it writes a line break after reading the user input (`false` by default).
+    ///
+    /// It adds a blank line between the user input and the next displayed text.
+    custom_render / with_custom_render: Option<Render>,
+    /// Defines a custom function to render the fields (`None` by default).
+    ///
+    /// If it is provided, the written and selected fields call it to display their message
+    /// (and their list for the selected fields) instead of using the built-in layout.
+    /// The suffix is still displayed right before the user input.
+    ///
+    /// For both kinds of fields, the line ending is written after the rendered text
+    /// if the `line_brk` specification is set, so the render function does not end
+    /// with a line break.
+    ///
+    /// See [`FieldParts`] for more information.
+    quiet / with_quiet: bool,
+    /// Defines if it suppresses the display of the fields (`false` by default).
+    ///
+    /// If it does, nothing is written to the stream: the messages, the suffix
+    /// and the invalid input message are skipped, and the user input is read directly.
+    /// It is useful to run silently with an input given in advance, for a batch execution.
+    index_pad / with_index_pad: Option<char>,
+    /// Defines the character used to pad the indexes of the selectable fields
+    /// (`None` by default).
+    ///
+    /// If it is provided, the indexes are padded on the left to the width of the largest index,
+    /// for instance with `' '` or `'0'`, so the labels start at the same column.
+    show_index / with_show_index: bool,
+    /// Defines if it displays the indexes of the selectable fields (`true` by default).
+    ///
+    /// If it does not, the fields are displayed with their bare labels, and the user
+    /// selects a field by typing its label, whatever the [match mode](MatchMode) is.
+    line_ending / with_line_ending: LineEnding,
+    /// Defines the line ending written after each displayed line (`LineEnding::Lf` by default).
+    ///
+    /// See [`LineEnding`] for more information.
+    show_range / with_show_range: bool,
+    /// Defines if it displays the range of the indexes before the suffix, when selecting
+    /// a value (`false` by default).
+    ///
+    /// The range is written between the index surroundings, for instance `"[1-3] >> "`.
+    /// It is not displayed if the indexes are hidden (see the `show_index` specification).
+    index_start / with_index_start: usize,
+    /// Defines the index of the first selectable field (`1` by default).
+    ///
+    /// The indexes are displayed and parsed from this base, for instance `0` for a zero-based
+    /// numbering mirroring the indexes of an array. The selected value is still the one
+    /// displayed with the entered index.
+    decorate / with_decorate: Option<fn(&str) -> String>,
+    /// Defines a function transforming the messages before displaying them (`None` by default).
+    ///
+    /// It is applied to the messages of the written and selectable fields, including when
+    /// they are given to a custom render function, and to the titles of the menus,
+    /// for instance to add a symbol or brackets, or to change their casing.
+    /// The labels of the selectable values are not transformed.
+    echo_msg / with_echo_msg: &'a str,
+    /// Defines the message written before the label of the selected value, when the field
+    /// echoes the choice (`"Selected: "` by default).
+    ///
+    /// It is written after the prefix, for instance to translate it.
+    /// See [`Selected::echo_choice`] for more information.
 );
 
+/// Function used to render a field with a custom layout.
+///
+/// See the `custom_render` format specification of [`Format`] for more information.
+pub type Render = fn(&FieldParts<'_>, &mut dyn fmt::Write) -> fmt::Result;
+
+/// The parts of a field, given to a custom [render](Render) function.
+///
+/// # Example
+///
+/// ```
+/// use ezmenulib::prelude::*;
+/// use std::fmt;
+///
+/// fn render(parts: &FieldParts<'_>, w: &mut dyn fmt::Write) -> fmt::Result {
+///     write!(w, "{} {}?", parts.prefix, parts.msg)?;
+///     for (i, label) in parts.labels.iter().enumerate() {
+///         write!(w, "\n  {}{}{}", i + 1, parts.chip, label)?;
+///     }
+///     Ok(())
+/// }
+///
+/// let fmt = Format::custom_render(Some(render));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FieldParts<'p> {
+    /// The prefix of the format.
+    pub prefix: &'p str,
+    /// The message of the field.
+    pub msg: &'p str,
+    /// The chip of the format.
+    pub chip: &'p str,
+    /// The default value of the field, if it is provided.
+    ///
+    /// For the selected fields, it corresponds to the label of the default field.
+    pub default: Option<&'p str>,
+    /// The example of the field, if it is provided.
+    pub example: Option<&'p str>,
+    /// Whether the field is optional or not.
+    pub optional: bool,
+    /// The labels of the selectable fields (empty for a written field).
+    pub labels: &'p [&'p str],
+}
+
+/// Defines how the user input is matched against the available fields
+/// when selecting a value.
+///
+/// An input matches an index if it is a number between `1` and the amount of fields.
+/// It matches a label if it is equal to the field message, ignoring the ASCII case
+/// and the surrounding whitespaces.
+///
+/// If several fields share the same label, the first one in the list is selected.
+/// When both an index and a label match the input (for instance a field labeled `"2"`),
+/// the precedence of the mode decides which field is selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchMode {
+    /// Tries to match the input as an index, then as a label.
+    IndexFirst,
+    /// Tries to match the input as a label, then as an index.
+    LabelFirst,
+    /// Only matches the input as an index.
+    IndexOnly,
+    /// Only matches the input as a label.
+    LabelOnly,
+}
+
+impl Default for MatchMode {
+    fn default() -> Self {
+        DEFAULT_FMT.match_mode
+    }
+}
+
+/// Defines the line ending written by the fields and the menus after each displayed line.
+///
+/// It is useful when the output is written to a file or a stream expecting
+/// CRLF line endings, for instance for Windows tools or network protocols.
+/// The line ending is displayed with its `Display` implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// The `"\n"` line ending.
+    Lf,
+    /// The `"\r\n"` line ending.
+    CrLf,
+}
+
+impl LineEnding {
+    /// Returns the line ending as a string slice.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
+impl Default for LineEnding {
+    fn default() -> Self {
+        DEFAULT_FMT.line_ending
+    }
+}
+
+impl Display for LineEnding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Defines the answer of a confirmation prompt accepting a quit token
+/// (see [`Written::quit`] and [`Written::confirm`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Confirm {
+    /// The user accepted.
+    Yes,
+    /// The user refused.
+    No,
+    /// The user entered the quit token.
+    Quit,
+}
+
+impl Confirm {
+    /// Returns the answer as a boolean, or `None` if the user entered the quit token.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Yes => Some(true),
+            Self::No => Some(false),
+            Self::Quit => None,
+        }
+    }
+}
+
+impl From<bool> for Confirm {
+    fn from(b: bool) -> Self {
+        match b {
+            true => Self::Yes,
+            false => Self::No,
+        }
+    }
+}
+
+/// Defines what an empty input means for a written field (see [`Written::on_empty`]).
+///
+/// The end of the input stream is handled like an empty input, except with
+/// [`EmptyBehavior::Reprompt`], where it returns a [`MenuError::Eof`] error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmptyBehavior<'a> {
+    /// Uses the default value of the field, if any (see [`Written::default_value`]).
+    /// Otherwise, the optional methods return `None`, and the other ones prompt again.
+    ///
+    /// This is the default behavior.
+    #[default]
+    Default,
+    /// Ignores the default value, so the optional methods return `None`,
+    /// and the other ones prompt again.
+    Skip,
+    /// Prompts the field again, even with the optional methods.
+    Reprompt,
+    /// Uses the given value as if the user entered it, instead of the default value.
+    Value(&'a str),
+}
+
+/// Defines a common normalization of the user input of a written field,
+/// applied before parsing it.
+///
+/// See [`Written::normalize`] for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Trims the input and converts it to lowercase.
+    Lower,
+    /// Trims the input and converts it to uppercase.
+    Upper,
+    /// Only trims the input.
+    TrimOnly,
+    /// Trims the input and replaces each sequence of whitespaces with a single space.
+    CollapseWhitespace,
+}
+
+impl Normalization {
+    /// Returns the given input normalized.
+    pub fn apply(self, s: &str) -> String {
+        let s = s.trim();
+        match self {
+            Self::Lower => s.to_lowercase(),
+            Self::Upper => s.to_uppercase(),
+            Self::TrimOnly => s.to_owned(),
+            Self::CollapseWhitespace => s.split_whitespace().collect::<Vec<_>>().join(" "),
+        }
+    }
+}
+
 /// Default formatting for a field is `"--> "` as a chip and `">> "` as prefix.
 ///
 /// This being, the field is printed like above (text between `[` and `]` is optional
@@ -121,6 +427,16 @@ impl<'a> Default for Format<'a> {
     }
 }
 
+impl Format<'_> {
+    /// Returns the given message transformed by the `decorate` specification, if any.
+    pub(crate) fn decorated<'s>(&self, msg: &'s str) -> Cow<'s, str> {
+        match self.decorate {
+            Some(decorate) => Cow::Owned(decorate(msg)),
+            None => Cow::Borrowed(msg),
+        }
+    }
+}
+
 /// Defines the behavior for a written value provided by the user.
 ///
 /// Like the [selected](Selected) values, it contains its own [format](Format),
@@ -132,6 +448,20 @@ impl<'a> Default for Format<'a> {
 /// It provides functions to define how to retrieve the value from the user.
 /// You have to provide a mutable reference to a [`MenuStream`] to retrieve the value.
 ///
+/// # Empty input
+///
+/// When the user enters an empty input, or when the input stream reached its end,
+/// the field resolves it with the following precedence:
+///
+/// 1. If the field has a default value, the default value is returned, even for
+///    the optional methods (such as [`Written::optional_value`]). It is not the case if the
+///    default value is only a suggestion (see [`Written::suggest_default`]).
+/// 2. Otherwise, if the field is optional, `None` is returned.
+/// 3. Otherwise, the field is required: it is prompted again for an empty input,
+///    and it returns a [`MenuError::Eof`] error if the input stream reached its end.
+///
+/// This behavior can be changed for each field with [`Written::on_empty`].
+///
 /// # Example
 ///
 /// For a make-license CLI program for example, you can use it like below:
@@ -144,32 +474,100 @@ impl<'a> Default for Format<'a> {
 ///     .many_values(&mut MenuStream::default(), ", ")?;
 /// # Ok(()) }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Written<'a> {
-    msg: &'a str,
+    msg: Cow<'a, str>,
     /// The format of the written field value.
     pub fmt: Format<'a>,
     example: Option<&'a str>,
     default: Option<String>,
+    line_brk: Option<bool>,
+    prefill: bool,
+    transform: Option<fn(String) -> String>,
+    on_parse_error: Option<fn(&str) -> String>,
+    undo_token: Option<&'a str>,
+    live_validate: Option<fn(&str) -> bool>,
+    on_empty: EmptyBehavior<'a>,
+    arg: Option<String>,
+    boolean: bool,
+    quit: Option<&'a str>,
+    normalization: Option<Normalization>,
+    secret: bool,
+    radix: Option<u32>,
+    suggest: bool,
+    bounds: Option<Bounds>,
+    io_retries: usize,
+    back_key: Option<&'a str>,
+}
+
+/// The bounds of the values of a written field, written as strings to be displayed,
+/// with the function checking that an input is contained in them.
+#[derive(Debug, Clone)]
+struct Bounds {
+    min: String,
+    max: String,
+    ty: &'static str,
+    check: fn(&str, &str, &str) -> bool,
+}
+
+/// Returns `true` if the given input is contained in the given bounds,
+/// once they are parsed as `T`.
+fn in_bounds<T: FromStr + PartialOrd>(s: &str, min: &str, max: &str) -> bool {
+    match (s.parse::<T>(), min.parse::<T>(), max.parse::<T>()) {
+        (Ok(v), Ok(min), Ok(max)) => min <= v && v <= max,
+        _ => false,
+    }
 }
 
 impl<'a> From<&'a str> for Written<'a> {
     fn from(msg: &'a str) -> Self {
+        Self::inner_new(Cow::Borrowed(msg))
+    }
+}
+
+/// Builds the written field from an owned message, for instance a message
+/// formatted at runtime.
+impl From<String> for Written<'_> {
+    fn from(msg: String) -> Self {
+        Self::inner_new(Cow::Owned(msg))
+    }
+}
+
+impl<'a> Written<'a> {
+    fn inner_new(msg: Cow<'a, str>) -> Self {
         Self {
             msg,
             fmt: Format::default(),
             example: None,
             default: None,
+            line_brk: None,
+            prefill: false,
+            transform: None,
+            on_parse_error: None,
+            undo_token: None,
+            live_validate: None,
+            on_empty: EmptyBehavior::Default,
+            arg: None,
+            boolean: false,
+            quit: None,
+            normalization: None,
+            secret: false,
+            radix: None,
+            suggest: false,
+            bounds: None,
+            io_retries: 0,
+            back_key: None,
         }
     }
 }
 
 impl Display for Written<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        self.fmt_with(f, &self.fmt, false)?;
-        f.write_str(match self.fmt.line_brk {
-            true => "\n",
-            false => self.fmt.suffix,
+        let fmt = self.merged_fmt(&self.fmt);
+        self.fmt_with(f, &fmt, false)?;
+        f.write_str(match fmt.line_brk {
+            true => fmt.line_ending.as_str(),
+            false => fmt.suffix,
         })
     }
 }
@@ -181,51 +579,130 @@ impl<'a> Written<'a> {
     /// This is used to prompt the written field with a given [`Format`]
     /// (see [`Written::prompt_with`] function for example).
     fn fmt_with<S: fmt::Write>(&self, s: &mut S, fmt: &Format<'_>, opt: bool) -> fmt::Result {
-        s.write_str(fmt.prefix)?;
-        s.write_str(self.msg)?;
+        if let Some(render) = fmt.custom_render {
+            render(
+                &FieldParts {
+                    prefix: fmt.prefix,
+                    msg: &fmt.decorated(&self.msg),
+                    chip: fmt.chip,
+                    default: self.default.as_deref().filter(|_| !self.secret),
+                    example: self.example,
+                    optional: opt,
+                    labels: &[],
+                },
+                s,
+            )?;
+            return match fmt.line_brk {
+                true => s.write_str(fmt.line_ending.as_str()),
+                false => Ok(()),
+            };
+        }
 
-        // Field details
-        if opt || self.example.is_some() || self.default.is_some() {
-            s.write_str(" (")?;
-
-            // - Example
-            if let Some(e) = self.example {
-                write!(s, "example: {}", e)?;
-                if opt || self.fmt.show_default && self.default.is_some() {
-                    s.write_str(", ")?;
+        s.write_str(fmt.prefix)?;
+        s.write_str(&fmt.decorated(&self.msg))?;
+
+        let opt = opt && fmt.mark_optional;
+
+        // The default value of a boolean field is shown in the hint,
+        // and the one of a secret field is never shown.
+        let show_default = fmt.show_default && !self.boolean && !self.secret;
+        if self.boolean {
+            let default = self.default.as_deref();
+            let (yes, no) = match default.and_then(parse_bool) {
+                Some(true) => ("Y", "n"),
+                Some(false) => ("y", "N"),
+                None => ("y", "n"),
+            };
+            match self.quit {
+                Some(q) if default.is_some_and(|d| d.eq_ignore_ascii_case(q)) => {
+                    write!(s, " [{}/{}/{}]", yes, no, q.to_uppercase())?
                 }
+                Some(q) => write!(s, " [{}/{}/{}]", yes, no, q)?,
+                None => write!(s, " [{}/{}]", yes, no)?,
             }
+        }
 
-            // - Default
-            match self.default {
-                Some(ref d) if self.fmt.show_default => write!(s, "default: {}", d)?,
-                _ => (),
-            }
-
-            // - Optional
-            if opt && self.default.is_none() {
-                s.write_str("optional")?;
-            }
-
-            s.write_str(")")?;
+        // Field details
+        let mut details = Vec::new();
+        // - Bounds
+        if let Some(ref b) = self.bounds {
+            details.push(format!("{}–{}", b.min, b.max));
+        }
+        // - Example
+        if let Some(e) = self.example {
+            details.push(format!("example: {}", e));
+        }
+        // - Default
+        match self.default {
+            Some(ref d) if show_default => details.push(format!("default: {}", d)),
+            _ => (),
+        }
+        // - Optional
+        if opt && self.default.is_none() {
+            details.push("optional".to_owned());
+        }
+        if !details.is_empty() {
+            write!(s, " ({})", details.join(", "))?;
         }
 
         match fmt.line_brk {
-            true => s.write_char('\n'),
+            true => s.write_str(fmt.line_ending.as_str()),
             false => Ok(()),
         }
     }
 
+    /// Shows the message used when the user input is incorrect, followed by the given errors,
+    /// or the message produced from the input if the field has a function for it
+    /// (see [`Written::on_parse_error`]).
+    ///
+    /// The input is the argument of the field if `arg` is `true`,
+    /// otherwise it is the last input read by the stream.
+    fn show_invalid_input<R, W: Write>(
+        &self,
+        fmt: &Format<'_>,
+        stream: &mut MenuStream<R, W>,
+        arg: bool,
+        errors: &[String],
+    ) -> MenuResult {
+        let input = match self.arg {
+            Some(ref a) if arg => Some(a.as_str()),
+            _ => stream.last_input(),
+        };
+        match (self.on_parse_error, input) {
+            (Some(f), Some(s)) if !s.is_empty() => {
+                if fmt.quiet {
+                    return Ok(());
+                }
+                let msg = f(s);
+                write!(stream, "{}{}", msg, fmt.line_ending).map_err(MenuError::from)
+            }
+            _ => show_invalid_errors(fmt, stream, errors),
+        }
+    }
+
+    /// Returns the merged version between the format of the written field and the given format,
+    /// with the line break overridden by the field if it is provided
+    /// (see [`Written::inline`] and [`Written::block`]).
+    fn merged_fmt<'f>(&'f self, fmt: &Format<'f>) -> Format<'f> {
+        let fmt = self.fmt.merged(fmt);
+        Format {
+            line_brk: self.line_brk.unwrap_or(fmt.line_brk),
+            ..fmt
+        }
+    }
+
     fn first_line<R, W: Write>(
         &self,
         stream: &mut MenuStream<R, W>,
         fmt: &Format<'_>,
         opt: bool,
     ) -> MenuResult {
-        if fmt.line_brk {
-            self.fmt_with(stream, fmt, opt)?;
-        }
-        Ok(())
+        retry_io(self.io_retries, || {
+            if fmt.line_brk && !fmt.quiet {
+                self.fmt_with(stream, fmt, opt)?;
+            }
+            Ok(())
+        })
     }
 
     /// Displays the second line according to the format, and returns the output
@@ -236,11 +713,131 @@ impl<'a> Written<'a> {
         fmt: &Format<'_>,
         opt: bool,
     ) -> MenuResult<String> {
-        if !fmt.line_brk {
-            self.fmt_with(stream, fmt, opt)?;
+        let s = self.prompt_raw_line(stream, fmt, opt)?;
+
+        // The back key of a form is returned as entered.
+        match self.back_key {
+            Some(key) if s == key => Ok(s),
+            _ => Ok(self.transformed(s)),
+        }
+    }
+
+    /// Displays the second line according to the format, and returns the input
+    /// as entered by the user, before it is transformed.
+    fn prompt_raw_line<R: BufRead, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'_>,
+        opt: bool,
+    ) -> MenuResult<String> {
+        retry_io(self.io_retries, || {
+            if !fmt.line_brk && !fmt.quiet {
+                self.fmt_with(stream, fmt, opt)?;
+            }
+
+            match (&self.default, self.live_validate) {
+                _ if self.secret => prompt_masked(fmt, stream),
+                (Some(default), _) if self.prefill => prompt_prefilled(fmt, stream, default),
+                (_, Some(validate)) => prompt_validated(fmt, stream, validate),
+                _ => prompt(fmt, stream),
+            }
+        })
+    }
+
+    /// Returns the given input normalized and transformed by the field
+    /// (see [`Written::normalize`] and [`Written::transform`]).
+    fn transformed(&self, s: String) -> String {
+        let s = match self.normalization {
+            Some(n) => n.apply(&s),
+            None => s,
+        };
+        match self.transform {
+            Some(f) => f(s),
+            None => s,
+        }
+    }
+
+    /// Returns the given value written as expected by the `FromStr` implementation
+    /// of the output type.
+    ///
+    /// For a boolean field (see [`Written::boolean`]), it returns `"true"` or `"false"`
+    /// if the value is a boolean. For an integer field written in another radix
+    /// (see [`Written::radix`]), it returns the integer written in decimal, or `None`
+    /// if it contains incorrect digits. Otherwise, it returns the value unchanged.
+    fn value_normalized<'s>(&self, s: &'s str) -> Option<Cow<'s, str>> {
+        if let Some(radix) = self.radix {
+            return parse_radix(s, radix).map(Cow::Owned);
+        }
+        Some(match parse_bool(s) {
+            Some(true) if self.boolean => Cow::Borrowed("true"),
+            Some(false) if self.boolean => Cow::Borrowed("false"),
+            _ => Cow::Borrowed(s),
+        })
+    }
+
+    /// Returns the default value of the field, if it is applied when the user input
+    /// is empty or incorrect (see [`Written::suggest_default`]).
+    fn applied_default(&self) -> Option<&str> {
+        self.default.as_deref().filter(|_| !self.suggest)
+    }
+
+    /// Returns the value used for an empty input, according to the behavior of the field
+    /// (see [`Written::on_empty`]).
+    fn empty_value(&self) -> Option<&str> {
+        match self.on_empty {
+            EmptyBehavior::Default => self.applied_default(),
+            EmptyBehavior::Value(v) => Some(v),
+            EmptyBehavior::Skip | EmptyBehavior::Reprompt => None,
+        }
+    }
+
+    /// Returns `true` if the end of the input stream can be handled like an empty input.
+    fn eof_as_empty(&self, opt: bool) -> bool {
+        self.on_empty != EmptyBehavior::Reprompt && (opt || self.empty_value().is_some())
+    }
+
+    /// Returns the argument given to the field, transformed and parsed by `parse`,
+    /// or `None` if no argument has been given.
+    fn arg_value<T, P: Fn(&str) -> Option<T>>(&self, parse: P) -> Option<Option<T>> {
+        let arg = self.transformed(self.arg.clone()?);
+        Some(parse(arg.trim()))
+    }
+
+    /// Returns `true` if the given input is contained in the bounds of the field,
+    /// if any (see [`Written::range`]).
+    ///
+    /// An input that cannot be parsed as the type of the bounds is out of bounds.
+    fn in_bounds(&self, s: &str) -> bool {
+        match self.bounds {
+            Some(ref b) => (b.check)(s, &b.min, &b.max),
+            None => true,
+        }
+    }
+
+    /// Parses the given value with `parse` once normalized (see [`Written::value_normalized`]),
+    /// if it is contained in the bounds of the field.
+    ///
+    /// The back key of a form is parsed as entered, whatever the bounds.
+    fn parse_bounded_with<T, P: Fn(&str) -> Option<T>>(&self, s: &str, parse: P) -> Option<T> {
+        if self.back_key == Some(s) {
+            return parse(s);
         }
+        let v = self.value_normalized(s)?;
+        parse(&v).filter(|_| self.in_bounds(&v))
+    }
+
+    /// Defines the key the user enters to go back to the previous field of a form.
+    ///
+    /// The key is returned as entered, without being transformed nor checked
+    /// against the bounds of the field.
+    pub(crate) fn with_back_key(mut self, key: &'a str) -> Self {
+        self.back_key = Some(key);
+        self
+    }
 
-        prompt(fmt.suffix, stream)
+    /// Parses the given value, if it is contained in the bounds of the field.
+    fn parse_bounded<T: FromStr>(&self, s: &str) -> Option<T> {
+        self.parse_bounded_with(s, |s| s.parse().ok())
     }
 
     /// Gives a custom formatting for the written field.
@@ -256,6 +853,32 @@ impl<'a> Written<'a> {
         self
     }
 
+    /// Displays the suffix right after the message of the field, on the same line,
+    /// whatever the line break of the given format is.
+    ///
+    /// Unlike a custom format with the [`Format::line_brk`] specification, the other
+    /// specifications are still inherited from the format of the container.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let age = Written::from("Age").inline();
+    /// ```
+    pub fn inline(mut self) -> Self {
+        self.line_brk = Some(false);
+        self
+    }
+
+    /// Displays the suffix on a separate line from the message of the field,
+    /// whatever the line break of the given format is.
+    ///
+    /// See [`Written::inline`] for more information.
+    pub fn block(mut self) -> Self {
+        self.line_brk = Some(true);
+        self
+    }
+
     /// Gives the default value accepted by the field.
     ///
     /// If the value type is incorrect, the [`Written::prompt`] function and its variations
@@ -269,87 +892,613 @@ impl<'a> Written<'a> {
         self
     }
 
-    /// Gives the default value of the field, passed by an environment variable.
+    /// Defines if the default value pre-fills the input of the user (`false` by default),
+    /// so the user can edit it rather than typing it again.
     ///
-    /// If the provided environment variable is incorrect, it will return an error
-    /// (See [`MenuError::EnvVar`] variant).
+    /// It requires the `crossterm` feature, and the standard input to be a terminal,
+    /// because the key events are read in raw mode. Otherwise, the default value is only
+    /// returned if the user entered an empty input.
     ///
-    /// If the value type of the variable is incorrect, the [`Written::prompt`] function
-    /// and its variations will panic at runtime.
+    /// # Note
+    ///
+    /// In raw mode, the key events are read from the terminal, not from the reader
+    /// of the stream.
+    pub fn prefill(mut self, prefill: bool) -> Self {
+        self.prefill = prefill;
+        self
+    }
+
+    /// Defines the function validating the user input while the user types it,
+    /// for a field expecting a strict format, such as a date.
+    ///
+    /// The function receives the input being typed, and returns `true` if it could
+    /// still become correct. The suffix is displayed in red as long as it returns `false`,
+    /// and it is redrawn on each keystroke. It only checks the input while it is typed:
+    /// the submitted input is checked as usual by parsing it, so a complete check
+    /// must be given with [`Values::written_until`](crate::menu::Values::written_until)
+    /// for instance.
+    ///
+    /// It requires the `crossterm` feature, and the standard input to be a terminal,
+    /// because the key events are read in raw mode. Otherwise, it has no effect.
+    /// It has no effect on a secret or pre-filled field either
+    /// (see [`Written::secret_env`] and [`Written::prefill`]).
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use ezmenulib::prelude::*;
-    /// # use std::error::Error;
-    /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// let user: String = Written::from("What is your name?")
-    ///     .default_env("USERNAME")?
-    ///     .prompt(&mut MenuStream::default())?;
-    /// # Ok(())
-    /// # }
+    /// let date: String = Written::from("Date (YYYY-MM-DD)")
+    ///     .live_validate(|s| {
+    ///         s.len() <= 10
+    ///             && s.chars().enumerate().all(|(i, c)| match i {
+    ///                 4 | 7 => c == '-',
+    ///                 _ => c.is_ascii_digit(),
+    ///             })
+    ///     })
+    ///     .prompt(&mut MenuStream::default())
+    ///     .unwrap();
     /// ```
-    pub fn default_env(mut self, var: &'a str) -> MenuResult<Self> {
-        self.default = Some(env::var(var).map_err(|e| MenuError::EnvVar(var.to_owned(), e))?);
-        Ok(self)
-    }
-
-    /// Gives an example of correct value for the field.
     ///
-    /// Obviously, it is better to give a correct value for the user as example,
-    /// but if the value is incorrect, it will only mislead the user,
-    /// and unlike the default value providing, the program will not panic at runtime
-    /// to emphasize the problem.
+    /// # Note
     ///
-    /// The example will be shown inside parenthesis according to its formatting
-    /// (see [`Format`] for more information).
-    pub fn example(mut self, example: &'a str) -> Self {
-        self.example = Some(example);
+    /// In raw mode, the key events are read from the terminal, not from the reader
+    /// of the stream.
+    pub fn live_validate(mut self, validate: fn(&str) -> bool) -> Self {
+        self.live_validate = Some(validate);
         self
     }
 
-    /// Prompts the field once, using the given prefix.
+    /// Defines the function transforming the user input before parsing it.
     ///
-    /// It checks the `line_brk` specification. If it is on `true`, the suffix is displayed
-    /// on a separate line, thus it will only display the suffix. Otherwise, it prints out
-    /// the whole message with the suffix.
-    fn prompt_once<R: BufRead, W: Write, T: FromStr>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        fmt: &Format<'_>,
-        opt: bool,
-    ) -> MenuResult<Option<T>> {
-        fn default_output<T: FromStr>(d: &str) -> T {
-            d.parse().unwrap_or_else(|_| default_failed::<T>(d))
-        }
-
-        let s = self.prompt_line(stream, fmt, opt)?;
-
-        if s.is_empty() {
-            return Ok(self.default.as_deref().map(default_output));
-        }
-
-        let out = s
-            .parse()
-            .ok()
-            .or_else(|| self.default.as_deref().map(default_output));
-
-        Ok(out)
+    /// It is applied to each input, before the constraint of the field is checked,
+    /// for instance to uppercase a country code or to strip the dashes of a card number.
+    /// The default value is not transformed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let code: String = Written::from("Country code")
+    ///     .transform(|s| s.to_uppercase())
+    ///     .prompt(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn transform(mut self, transform: fn(String) -> String) -> Self {
+        self.transform = Some(transform);
+        self
     }
 
-    /// Prompts the field and returns the input, or `None` if the input is incorrect,
-    /// using the given format.
+    /// Defines the function producing the message shown when the user input is incorrect,
+    /// from the incorrect input.
     ///
-    /// It uses the merged version between the format of the written field and the given format.
+    /// The message is shown instead of the one of the format (see [`Format::invalid_msg`]),
+    /// before the field is prompted again, for instance to tell which part of a date
+    /// is incorrect. It is not shown if the input is empty, or if the format is quiet.
     ///
-    /// It prompts the field once, and if the user entered a correct input,
-    /// it returns `Some(value)`, otherwise, it attempts to return the default value
-    /// (see [`Written::default_value`] or [`Written::default_env`]), and if there is no
-    /// default value, it returns `None`.
+    /// # Example
     ///
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let month: u8 = Written::from("Month")
+    ///     .on_parse_error(|s| format!("'{}' is not a valid month", s))
+    ///     .prompt_until(&mut MenuStream::default(), |m| (1..=12).contains(m))
+    ///     .unwrap();
+    /// ```
+    pub fn on_parse_error(mut self, on_parse_error: fn(&str) -> String) -> Self {
+        self.on_parse_error = Some(on_parse_error);
+        self
+    }
+
+    /// Defines the token removing the last value entered, when prompting many values
+    /// one per line (see [`Written::many_values_lines`]).
     ///
-    /// # Panics
+    /// The token is matched case-sensitively against the line as entered, before it is
+    /// normalized or transformed, and the field is prompted again after it,
+    /// so the user can fix a typo without entering the whole list again.
+    /// It has no effect if no value has been entered yet.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let steps: Vec<String> = Written::from("Enter the steps, then `done`")
+    ///     .undo_token(":undo")
+    ///     .many_values_lines(&mut MenuStream::default(), "done")?;
+    /// # Ok(()) }
+    /// ```
+    pub fn undo_token(mut self, token: &'a str) -> Self {
+        self.undo_token = Some(token);
+        self
+    }
+
+    /// Defines what an empty input means for the field (see [`EmptyBehavior`]).
+    ///
+    /// By default, an empty input uses the default value of the field, if any,
+    /// otherwise the optional methods return `None` and the other ones prompt again.
+    ///
+    /// # Panics
+    ///
+    /// With [`EmptyBehavior::Value`], if the value has an incorrect type,
+    /// the [`Written::prompt`] function and its variations will panic at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let nickname: Option<String> = Written::from("Nickname")
+    ///     .default_value("anonymous")
+    ///     .on_empty(EmptyBehavior::Skip)
+    ///     .optional_value(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn on_empty(mut self, behavior: EmptyBehavior<'a>) -> Self {
+        self.on_empty = behavior;
+        self
+    }
+
+    /// Returns the message of the field.
+    pub(crate) fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    /// Returns the message of the field, with the value to display in a summary
+    /// for the given last input of the user.
+    ///
+    /// If the input is empty, it corresponds to the argument, or to the value used
+    /// for an empty input (see [`Written::on_empty`]), if any.
+    pub(crate) fn recorded(&self, input: Option<String>) -> (String, String) {
+        let value = input
+            .filter(|s| !s.is_empty())
+            .or_else(|| self.arg.clone())
+            .or_else(|| self.empty_value().map(str::to_owned))
+            .unwrap_or_default();
+        let value = match self.secret {
+            true => "*".repeat(value.chars().count().min(8)),
+            false => value,
+        };
+        (self.msg.to_string(), value)
+    }
+
+    /// Returns `true` if the field resolved to its default value for the given last input
+    /// of the user, meaning that the input is empty, or that the user did not enter
+    /// any input while no argument was provided (see [`Written::arg`]).
+    ///
+    /// The default value is the value used for an empty input (see [`Written::on_empty`]).
+    pub(crate) fn used_default(&self, input: Option<&str>) -> bool {
+        self.empty_value().is_some() && input.map_or(self.arg.is_none(), str::is_empty)
+    }
+
+    /// Checks that the default value of the field is correct for the `T` output type,
+    /// without prompting the field.
+    ///
+    /// It is useful to check the fields at the start of the program, because prompting
+    /// a field with an incorrect default value panics at runtime. If the default value
+    /// is incorrect, or if the bounds of the field (see [`Written::range`]) cannot be parsed
+    /// as `T`, it returns a [`MenuError::Other`] error describing it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let age = Written::from("age").default_value("eighteen");
+    /// assert!(age.validate::<u8>().is_err());
+    /// ```
+    pub fn validate<T: FromStr>(&self) -> MenuResult {
+        if let Some(ref b) = self.bounds {
+            if b.min.parse::<T>().is_err() || b.max.parse::<T>().is_err() {
+                return Err(MenuError::from(format!(
+                    "the bounds `{}–{}` of `{}` type are incorrect for `{}` type",
+                    b.min,
+                    b.max,
+                    b.ty,
+                    std::any::type_name::<T>(),
+                )));
+            }
+        }
+        self.parsed_default::<T>()
+            .map_or(Ok(()), |res| res.map(|_| ()))
+    }
+
+    /// Returns the default value of the field parsed as `T`, without prompting the field,
+    /// or `None` if the field has no default value.
+    ///
+    /// The default value is parsed like when it is used by the field, so it is useful
+    /// to preview it, or to test it. Unlike prompting the field, it does not panic
+    /// if the default value is incorrect, but it returns a [`MenuError::Other`] error
+    /// describing it (see [`Written::validate`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let overwrite = Written::from("Overwrite?").boolean().default_value("yes");
+    /// assert!(overwrite.parsed_default::<bool>().unwrap().unwrap());
+    ///
+    /// let age = Written::from("age").default_value("eighteen");
+    /// assert!(age.parsed_default::<u8>().unwrap().is_err());
+    /// assert!(Written::from("name").parsed_default::<String>().is_none());
+    /// ```
+    pub fn parsed_default<T: FromStr>(&self) -> Option<MenuResult<T>> {
+        let default = self.default.as_deref()?;
+        Some(
+            self.value_normalized(default)
+                .and_then(|d| d.parse().ok())
+                .ok_or_else(|| MenuError::from(default_failed_msg::<T>(default))),
+        )
+    }
+
+    /// Defines the field as a boolean field, for a `bool` output type.
+    ///
+    /// The input is then accepted as `y`/`n`, `yes`/`no`, `1`/`0`, `on`/`off`,
+    /// or `true`/`false`, case-insensitively. The default value is accepted the same way,
+    /// and is displayed as a `[y/N]` hint after the message.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let overwrite: bool = Written::from("Overwrite the file?")
+    ///     .boolean()
+    ///     .default_value("no")
+    ///     .prompt(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn boolean(mut self) -> Self {
+        self.boolean = true;
+        self
+    }
+
+    /// Defines the field as a confirmation field accepting a quit token, such as `"q"`,
+    /// besides the yes and no answers.
+    ///
+    /// It also defines the field as a boolean field (see [`Written::boolean`]),
+    /// and the token is displayed in the hint, for instance `[y/n/q]`.
+    /// The field must then be prompted with [`Written::confirm`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let mut stream = MenuStream::default();
+    /// let field = Written::from("Delete the next file?").quit("q");
+    /// while let Some(delete) = field.confirm(&mut stream)?.as_bool() {
+    ///     // ...
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn quit(mut self, token: &'a str) -> Self {
+        self.boolean = true;
+        self.quit = Some(token);
+        self
+    }
+
+    /// Gives the value passed as an argument of the program, used as the user input.
+    ///
+    /// If the argument is provided, it is parsed like a user input, and the field is not
+    /// prompted. If it is incorrect, the field is prompted normally.
+    /// If it is `None`, the field is prompted normally.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let name: String = Written::from("What is your name?")
+    ///     .arg(std::env::args().nth(1))
+    ///     .prompt(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn arg(mut self, arg: Option<String>) -> Self {
+        self.arg = arg;
+        self
+    }
+
+    /// Defines the field as a secret, read from the given environment variable if it is set,
+    /// or prompted with a masked input otherwise.
+    ///
+    /// If the environment variable is set, it is used like an [argument](Written::arg),
+    /// so the field is not prompted. An argument already given to the field takes precedence
+    /// over the environment variable. The value of a secret field is never displayed,
+    /// neither as the default value after the message, nor in the summary of the
+    /// recorded answers (see [`Values::record`]), and only its mask is kept
+    /// as the last input of the stream.
+    ///
+    /// # Note
+    ///
+    /// The input is masked only with the `crossterm` feature, and if the standard input
+    /// is a terminal, by reading the key events in raw mode. Without the `crossterm` feature,
+    /// the input is read from the stream like any input, so **the secret is echoed
+    /// by the terminal** while the user types it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let token: String = Written::from("API token")
+    ///     .secret_env("API_TOKEN")
+    ///     .prompt(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn secret_env(self, var: &str) -> Self {
+        self.secret_env_with(var, |var| env::var(var).ok())
+    }
+
+    /// Defines the field as a secret, read from the given variable with the `lookup`
+    /// function if it is found, or prompted with a masked input otherwise.
+    ///
+    /// It is useful to read the secret from another source than the environment,
+    /// such as a configuration file. See [`Written::secret_env`] for more information.
+    pub fn secret_env_with(mut self, var: &str, lookup: fn(&str) -> Option<String>) -> Self {
+        self.arg = self.arg.or_else(|| lookup(var));
+        self.secret = true;
+        self
+    }
+
+    /// Defines the bounds of the values written by the user, displayed after the message,
+    /// for instance `"Age (1–120)"`.
+    ///
+    /// Once the input is parsed as the output type, it is parsed as `T` to check that it is
+    /// contained in the range. If the input is out of bounds, or cannot be parsed as `T`,
+    /// the field is prompted again. For many values, each value must be contained
+    /// in the range. The default value is not checked.
+    ///
+    /// `T` should be the output type, or a type parsing the same inputs. A mismatch between
+    /// the bounds and the output type can be detected ahead of time with
+    /// [`Written::validate`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let age: u8 = Written::from("Age")
+    ///     .range(1..=120)
+    ///     .prompt(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn range<T>(mut self, range: RangeInclusive<T>) -> Self
+    where
+        T: FromStr + PartialOrd + Display,
+    {
+        self.bounds = Some(Bounds {
+            min: range.start().to_string(),
+            max: range.end().to_string(),
+            ty: std::any::type_name::<T>(),
+            check: in_bounds::<T>,
+        });
+        self
+    }
+
+    /// Defines the amount of times the field is displayed and prompted again
+    /// when writing to or reading from the stream fails with an IO error (`0` by default).
+    ///
+    /// It is useful in unreliable terminal environments, where the IO errors may be
+    /// transient. The retries are distinct from the prompts following an incorrect input.
+    /// Once the retries are exhausted, the IO error is returned.
+    pub fn io_retries(mut self, retries: usize) -> Self {
+        self.io_retries = retries;
+        self
+    }
+
+    /// Defines the default value of the field as a suggestion only.
+    ///
+    /// The default value is still displayed (or pre-filled, see [`Written::prefill`]),
+    /// but it is not applied when the user enters an empty or incorrect input:
+    /// the user is prompted again instead, so they must type the value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let branch: String = Written::from("Branch to deploy")
+    ///     .default_value("main")
+    ///     .suggest_default()
+    ///     .prompt(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn suggest_default(mut self) -> Self {
+        self.suggest = true;
+        self
+    }
+
+    /// Defines the radix of the integer written by the user, such as `16` for hexadecimal
+    /// values, for an integer output type.
+    ///
+    /// The input may start with the prefix corresponding to the radix, meaning
+    /// `0x` for hexadecimal, `0o` for octal and `0b` for binary values.
+    /// The default value is written in the same radix. If the input contains incorrect
+    /// digits, the field is prompted again.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let addr: u32 = Written::from("Address")
+    ///     .radix(16)
+    ///     .example("0x7ff0")
+    ///     .prompt(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the radix is not in the range from 2 to 36, this function will panic.
+    pub fn radix(mut self, radix: u32) -> Self {
+        assert!(
+            (2..=36).contains(&radix),
+            "the radix must be in the range from 2 to 36, got {}",
+            radix
+        );
+        self.radix = Some(radix);
+        self
+    }
+
+    /// Defines the normalization of the user input, such as converting it to lowercase.
+    ///
+    /// It is applied to each input before the [transformation](Written::transform)
+    /// of the field, if any, and before parsing it. The default value is not normalized.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let key: String = Written::from("Key")
+    ///     .normalize(Normalization::Lower)
+    ///     .prompt(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn normalize(mut self, normalization: Normalization) -> Self {
+        self.normalization = Some(normalization);
+        self
+    }
+
+    /// Gives the default value of the field, passed by an environment variable.
+    ///
+    /// If the provided environment variable is incorrect, it will return an error
+    /// (See [`MenuError::EnvVar`] variant).
+    ///
+    /// If the value type of the variable is incorrect, the [`Written::prompt`] function
+    /// and its variations will panic at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let user: String = Written::from("What is your name?")
+    ///     .default_env("USERNAME")?
+    ///     .prompt(&mut MenuStream::default())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn default_env(mut self, var: &'a str) -> MenuResult<Self> {
+        self.default = Some(env::var(var).map_err(|e| MenuError::env_var(var, e))?);
+        Ok(self)
+    }
+
+    /// Gives an example of correct value for the field.
+    ///
+    /// Obviously, it is better to give a correct value for the user as example,
+    /// but if the value is incorrect, it will only mislead the user,
+    /// and unlike the default value providing, the program will not panic at runtime
+    /// to emphasize the problem.
+    ///
+    /// The example will be shown inside parenthesis according to its formatting
+    /// (see [`Format`] for more information).
+    pub fn example(mut self, example: &'a str) -> Self {
+        self.example = Some(example);
+        self
+    }
+
+    /// Prompts the field once, using the given prefix.
+    ///
+    /// It checks the `line_brk` specification. If it is on `true`, the suffix is displayed
+    /// on a separate line, thus it will only display the suffix. Otherwise, it prints out
+    /// the whole message with the suffix.
+    ///
+    /// The input is parsed with the `parse` function, which is also used to parse the
+    /// default value. If `bounded` is `true`, the input is normalized and must be contained
+    /// in the bounds of the field, and an input with incorrect digits for the radix
+    /// of the field is not replaced by the default value.
+    fn prompt_once<R, W, T, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'_>,
+        opt: bool,
+        bounded: bool,
+        parse: F,
+    ) -> MenuResult<Option<T>>
+    where
+        R: BufRead,
+        W: Write,
+        F: Fn(&str) -> Option<T>,
+    {
+        let default_output = |d: &str| {
+            let out = match bounded {
+                true => self.value_normalized(d).and_then(|d| parse(&d)),
+                false => parse(d),
+            };
+            out.unwrap_or_else(|| default_failed::<T>(d))
+        };
+
+        // The end of the input stream is handled like an empty input,
+        // if the field can provide a value without any input.
+        let s = loop {
+            let s = match self.prompt_line(stream, fmt, opt) {
+                Err(MenuError::Eof) if self.eof_as_empty(opt) => String::new(),
+                res => res?,
+            };
+            if !s.is_empty() || self.on_empty != EmptyBehavior::Reprompt {
+                break s;
+            }
+        };
+
+        if s.is_empty() {
+            return Ok(self.empty_value().map(default_output));
+        }
+
+        if !bounded {
+            return Ok(parse(&s).or_else(|| self.applied_default().map(default_output)));
+        }
+        // The field is prompted again if the digits are incorrect for the radix.
+        if self.back_key != Some(s.as_str()) && self.value_normalized(&s).is_none() {
+            return Ok(None);
+        }
+        let out = self
+            .parse_bounded_with(&s, &parse)
+            .or_else(|| self.applied_default().map(default_output));
+
+        Ok(out)
+    }
+
+    /// Prompts the field until the input is parsed by `parse` and the constraint is applied,
+    /// using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    fn parse_until_with<R, W, T, P, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        parse: P,
+        til: F,
+        fmt: &Format<'_>,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        P: Fn(&str) -> Option<T>,
+        F: Fn(&T) -> bool,
+    {
+        let fmt = self.merged_fmt(fmt);
+        match self.arg_value(|s| self.parse_bounded_with(s, &parse)) {
+            Some(Some(out)) if til(&out) => return Ok(out),
+            Some(_) => self.show_invalid_input(&fmt, stream, true, &[])?,
+            None => (),
+        }
+        self.first_line(stream, &fmt, false)?;
+
+        // Loops while incorrect input.
+        loop {
+            match self.prompt_once(stream, &fmt, false, true, &parse)? {
+                Some(out) if til(&out) => return Ok(out),
+                _ => self.show_invalid_input(&fmt, stream, false, &[])?,
+            }
+        }
+    }
+
+    /// Prompts the field and returns the input, or `None` if the input is incorrect,
+    /// using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    ///
+    /// It prompts the field once, and if the user entered a correct input,
+    /// it returns `Some(value)`, otherwise, it attempts to return the default value
+    /// (see [`Written::default_value`] or [`Written::default_env`]), and if there is no
+    /// default value, it returns `None`.
+    ///
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
+    ///
+    /// # Panics
     ///
     /// If the default value has an incorrect type, this function will panic.
     pub fn optional_value_with<R, W, T>(
@@ -362,9 +1511,14 @@ impl<'a> Written<'a> {
         W: Write,
         T: FromStr,
     {
-        let fmt = self.fmt.merged(fmt);
+        let fmt = self.merged_fmt(fmt);
+        match self.arg_value(|s| self.parse_bounded(s)) {
+            Some(Some(out)) => return Ok(Some(out)),
+            Some(None) => self.show_invalid_input(&fmt, stream, true, &[])?,
+            None => (),
+        }
         self.first_line(stream, &fmt, true)?;
-        self.prompt_once(stream, &fmt, true)
+        self.prompt_once(stream, &fmt, true, true, |s| s.parse().ok())
     }
 
     /// Prompts the field and returns the input, or `None` if the input is incorrect.
@@ -374,568 +1528,2326 @@ impl<'a> Written<'a> {
     /// (see [`Written::default_value`] or [`Written::default_env`]), and if there is no
     /// default value, it returns `None`.
     ///
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn optional_value<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<Option<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+    {
+        self.optional_value_with(stream, &self.fmt)
+    }
+
+    /// Prompts the field and returns the inputs as a `Vec<T>` until the given
+    /// constraint is applied to all the values, splitting the input with `sep`.
+    ///
+    /// If `keep_empty` is `false`, the values are trimmed and the empty ones are skipped.
+    fn many_values_inner<R, W, T, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: &str,
+        til: F,
+        fmt: &Format<'_>,
+        keep_empty: bool,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        F: Fn(&T) -> bool,
+    {
+        fn inner_prompt_once<R: BufRead, W: Write, T: FromStr>(
+            w: &Written<'_>,
+            stream: &mut MenuStream<R, W>,
+            sep: &str,
+            fmt: &Format<'_>,
+            keep_empty: bool,
+        ) -> MenuResult<Option<Vec<T>>> {
+            let output = |default: &str| {
+                let res: Result<Vec<T>, T::Err> = split_values(default, sep, keep_empty)
+                    .map(T::from_str)
+                    .collect();
+                res.unwrap_or_else(|_| default_failed::<T>(default))
+            };
+
+            let s = match w.prompt_line(stream, fmt, false) {
+                Err(MenuError::Eof) if w.eof_as_empty(false) => String::new(),
+                res => res?,
+            };
+
+            if s.is_empty() {
+                match (w.on_empty, w.empty_value()) {
+                    (EmptyBehavior::Reprompt, _) => return Ok(None),
+                    (_, Some(v)) => return Ok(Some(output(v))),
+                    _ => (),
+                }
+            }
+
+            let res: Option<Vec<T>> = split_values(&s, sep, keep_empty)
+                .map(|v| w.parse_bounded(v))
+                .collect();
+
+            Ok(res.or_else(|| w.applied_default().map(output)))
+        }
+
+        let fmt = self.merged_fmt(fmt);
+        let arg = self.arg_value(|s| {
+            split_values(s, sep, keep_empty)
+                .map(|v| self.parse_bounded(v))
+                .collect::<Option<Vec<T>>>()
+        });
+        match arg {
+            Some(Some(v)) if v.iter().all(&til) => return Ok(v),
+            Some(_) => self.show_invalid_input(&fmt, stream, true, &[])?,
+            None => (),
+        }
+        self.first_line(stream, &fmt, false)?;
+
+        // Loops while incorrect input.
+        loop {
+            match inner_prompt_once(self, stream, sep, &fmt, keep_empty)? {
+                Some(v) if v.iter().all(&til) => return Ok(v),
+                _ => self.show_invalid_input(&fmt, stream, false, &[])?,
+            }
+        }
+    }
+
+    /// Prompts the field and returns the inputs as a `Vec<T>` until the given
+    /// constraint is applied to all the values, using `sep` to split the input
+    /// into the output values, and using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    ///
+    /// The values are trimmed, and the empty ones are skipped. For instance, with `" "`
+    /// as separator, consecutive spaces are collapsed, and with `","` as separator,
+    /// `"a, ,b"` gives `["a", "b"]`. If you need to keep the empty values,
+    /// use [`Written::many_values_keep_empty_with`] instead.
+    /// If the input is empty, it returns the default value if it is provided.
+    ///
+    /// After checking and parsing the values provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn many_values_until_with<R, W, T, S, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+        til: F,
+        fmt: &Format<'_>,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+        F: Fn(&T) -> bool,
+    {
+        self.many_values_inner(stream, sep.as_ref(), til, fmt, false)
+    }
+
+    /// Prompts the field and returns the inputs as a `Vec<T>` until the given
+    /// constraint is applied to all the values, using `sep` to split the input
+    /// into the output values.
+    ///
+    /// After checking and parsing the values provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn many_values_until<R, W, T, S, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+        til: F,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+        F: Fn(&T) -> bool,
+    {
+        self.many_values_until_with(stream, sep, til, &self.fmt)
+    }
+
+    /// Prompts the field and returns the inputs as a `Vec<T>` using `sep` to split the input
+    /// into the output values, and using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    ///
+    /// After checking and parsing the values provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn many_values_with<R, W, T, S>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+        fmt: &Format<'_>,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+    {
+        self.many_values_until_with(stream, sep, keep, fmt)
+    }
+
+    /// Prompts the field and returns the inputs as a `Vec<T>` using `sep` to split the input
+    /// into the output values.
+    ///
+    /// After checking and parsing the values provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn many_values<R, W, T, S>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+    {
+        self.many_values_with(stream, sep, &self.fmt)
+    }
+
+    /// Prompts the field and returns the inputs as a `Vec<T>` using `sep` to split the input
+    /// into the output values, keeping the empty values, and using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    ///
+    /// Unlike [`Written::many_values_with`], the values are neither trimmed nor skipped
+    /// if they are empty, so `"a,,b"` with `","` as separator gives `["a", "", "b"]`.
+    /// This is useful when the empty values are meaningful, for CSV-like inputs.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn many_values_keep_empty_with<R, W, T, S>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+        fmt: &Format<'_>,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+    {
+        self.many_values_inner(stream, sep.as_ref(), keep, fmt, true)
+    }
+
+    /// Prompts the field and returns the inputs as a `Vec<T>` using `sep` to split the input
+    /// into the output values, keeping the empty values.
+    ///
+    /// See [`Written::many_values_keep_empty_with`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn many_values_keep_empty<R, W, T, S>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+    {
+        self.many_values_keep_empty_with(stream, sep, &self.fmt)
+    }
+
+    /// Prompts the field and returns exactly `N` values, using `sep` to split the input,
+    /// with the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// The values are trimmed, and the empty ones are skipped, like with
+    /// [`Written::many_values_with`]. If the input does not contain exactly `N` values,
+    /// or if a value is incorrect, the field is prompted again. If the format has
+    /// an [invalid message](Format::invalid_msg), the reason is shown after it,
+    /// for instance `"expected exactly 3 values"`.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type or amount of values, this function will panic.
+    pub fn array_values_with<R, W, T, S, const N: usize>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+        fmt: &Format<'_>,
+    ) -> MenuResult<[T; N]>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+    {
+        let sep = sep.as_ref();
+        let parse = |s: &str| {
+            let values: Vec<&str> = split_values(s, sep, false).collect();
+            if values.len() != N {
+                return Err(format!("expected exactly {} values", N));
+            }
+            let values: Option<Vec<T>> =
+                values.into_iter().map(|v| self.parse_bounded(v)).collect();
+            values
+                .and_then(|v| v.try_into().ok())
+                .ok_or_else(|| "incorrect value".to_owned())
+        };
+        // The bounds apply to each value, not to the whole input.
+        self.parse_any_with(stream, &[parse], fmt, false)
+    }
+
+    /// Prompts the field and returns exactly `N` values, using `sep` to split the input.
+    ///
+    /// See [`Written::array_values_with`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let [r, g, b]: [u8; 3] = Written::from("Color (r, g, b)")
+    ///     .example("255, 128, 0")
+    ///     .array_values(&mut MenuStream::default(), ",")?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type or amount of values, this function will panic.
+    pub fn array_values<R, W, T, S, const N: usize>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sep: S,
+    ) -> MenuResult<[T; N]>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        S: AsRef<str>,
+    {
+        self.array_values_with(stream, sep, &self.fmt)
+    }
+
+    /// Prompts the field for many values, one per line, and returns the values with
+    /// the inputs of the user.
+    pub(crate) fn many_lines_inner<R, W, T>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sentinel: &str,
+        fmt: &Format<'_>,
+    ) -> MenuResult<(Vec<T>, Vec<String>)>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+    {
+        let fmt = self.merged_fmt(fmt);
+        self.first_line(stream, &fmt, false)?;
+
+        let mut out = Vec::new();
+        let mut inputs = Vec::new();
+        loop {
+            let s = match self.prompt_raw_line(stream, &fmt, false) {
+                Err(MenuError::Eof) => break,
+                res => res?,
+            };
+            // The sentinel and the undo token are matched as entered,
+            // before the line is transformed.
+            if s.is_empty() || s == sentinel {
+                break;
+            }
+            if self.undo_token == Some(s.as_str()) {
+                out.pop();
+                inputs.pop();
+                continue;
+            }
+
+            let s = self.transformed(s);
+            match self.parse_bounded(&s) {
+                Some(value) => {
+                    out.push(value);
+                    inputs.push(s);
+                }
+                None => self.show_invalid_input(&fmt, stream, false, &[])?,
+            }
+        }
+
+        Ok((out, inputs))
+    }
+
+    /// Prompts the field for many values, one per line, until the user enters
+    /// the `sentinel` token, an empty line, or until the input stream reaches its end,
+    /// using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// Each line is parsed as a value, and the user is prompted again for an incorrect line.
+    /// The sentinel and the undo token are matched against the line as entered, before it
+    /// is normalized or transformed (see [`Written::normalize`] and [`Written::transform`]).
+    /// If the field has an undo token (see [`Written::undo_token`]), entering it removes
+    /// the last value entered.
+    /// This is useful for long or complex values, that are hard to split on a single line.
+    /// The default value is not used.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    pub fn many_values_lines_with<R, W, T>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sentinel: &str,
+        fmt: &Format<'_>,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+    {
+        Ok(self.many_lines_inner(stream, sentinel, fmt)?.0)
+    }
+
+    /// Prompts the field for a block of lines, until a line matches the given terminator,
+    /// and returns the whole block, including the terminator line.
+    ///
+    /// It is useful for pasted blocks, such as a PEM key ending with `-----END`,
+    /// that contain empty lines. The suffix is only displayed before the first line,
+    /// and each line is trimmed. The lines are joined with `"\n"`.
+    ///
+    /// It returns a [`MenuError::Eof`] error if the input stream reaches its end
+    /// before the terminator line.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let key = Written::from("Paste the private key")
+    ///     .lines_until(&mut MenuStream::default(), |l| l.starts_with("-----END"))?;
+    /// # Ok(()) }
+    /// ```
+    pub fn lines_until<R, W, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        terminator: F,
+    ) -> MenuResult<String>
+    where
+        R: BufRead,
+        W: Write,
+        F: Fn(&str) -> bool,
+    {
+        let fmt = self.merged_fmt(&self.fmt);
+        self.first_line(stream, &fmt, false)?;
+
+        let mut block = self.prompt_line(stream, &fmt, false)?;
+        let mut last = block.clone();
+        while !terminator(&last) {
+            last = self.transformed(read_input(stream)?);
+            block.push('\n');
+            block.push_str(&last);
+        }
+
+        Ok(block)
+    }
+
+    /// Prompts the field for a block of lines, until a line matches the given regular
+    /// expression, and returns the whole block, including the terminator line.
+    ///
+    /// See [`Written::lines_until`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let end = regex::Regex::new("^-----END [A-Z ]+-----$").unwrap();
+    /// let key = Written::from("Paste the private key")
+    ///     .lines_until_match(&mut MenuStream::default(), &end)?;
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "regex")]
+    #[cfg_attr(nightly, doc(cfg(feature = "regex")))]
+    pub fn lines_until_match<R, W>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        terminator: &regex::Regex,
+    ) -> MenuResult<String>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        self.lines_until(stream, |l| terminator.is_match(l))
+    }
+
+    /// Prompts the field for many values, one per line, until the user enters
+    /// the `sentinel` token, an empty line, or until the input stream reaches its end.
+    ///
+    /// See [`Written::many_values_lines_with`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let steps: Vec<String> = Written::from("Enter the steps, then `done`")
+    ///     .many_values_lines(&mut MenuStream::default(), "done")?;
+    /// # Ok(()) }
+    /// ```
+    pub fn many_values_lines<R, W, T>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        sentinel: &str,
+    ) -> MenuResult<Vec<T>>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+    {
+        self.many_values_lines_with(stream, sentinel, &self.fmt)
+    }
+
+    /// Prompts the field until the constraint is applied, using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// After checking and parsing the value provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_until_with<R, W, T, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        til: F,
+        fmt: &Format<'a>,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        F: Fn(&T) -> bool,
+    {
+        self.parse_until_with(stream, |s| s.parse().ok(), til, fmt)
+    }
+
+    /// Prompts the field until the constraint is applied.
+    ///
+    /// After checking and parsing the value provided by the user, it calls the `til` function.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_until<R, W, T, F>(&self, stream: &mut MenuStream<R, W>, til: F) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        F: Fn(&T) -> bool,
+    {
+        self.prompt_until_with(stream, til, &self.fmt)
+    }
+
+    /// Prompts the field until the asynchronous constraint is applied, using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// It is like [`Written::prompt_until_with`], but the `til` function returns a future,
+    /// awaited before accepting the value, for instance to check that a username is
+    /// available with a request to a service. The input is still read synchronously.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    #[cfg(feature = "async")]
+    #[cfg_attr(nightly, doc(cfg(feature = "async")))]
+    pub async fn prompt_until_async_with<R, W, T, F, Fut>(
+        &self,
+        stream: &mut MenuStream<'_, R, W>,
+        til: F,
+        fmt: &Format<'_>,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        F: Fn(&T) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        let fmt = self.merged_fmt(fmt);
+        let parse = |s: &str| s.parse().ok();
+        match self.arg_value(|s| self.parse_bounded_with(s, parse)) {
+            Some(Some(out)) => {
+                if til(&out).await {
+                    return Ok(out);
+                }
+                self.show_invalid_input(&fmt, stream, true, &[])?;
+            }
+            Some(None) => self.show_invalid_input(&fmt, stream, true, &[])?,
+            None => (),
+        }
+        self.first_line(stream, &fmt, false)?;
+
+        // Loops while incorrect input.
+        loop {
+            if let Some(out) = self.prompt_once(stream, &fmt, false, true, parse)? {
+                if til(&out).await {
+                    return Ok(out);
+                }
+            }
+            self.show_invalid_input(&fmt, stream, false, &[])?;
+        }
+    }
+
+    /// Prompts the field until the asynchronous constraint is applied.
+    ///
+    /// See [`Written::prompt_until_async_with`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # async fn is_available(name: &str) -> bool { true }
+    /// # async fn run() -> MenuResult {
+    /// let name: String = Written::from("Username")
+    ///     .prompt_until_async(&mut MenuStream::default(), |name: &String| {
+    ///         let name = name.clone();
+    ///         async move { is_available(&name).await }
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    #[cfg(feature = "async")]
+    #[cfg_attr(nightly, doc(cfg(feature = "async")))]
+    pub async fn prompt_until_async<R, W, T, F, Fut>(
+        &self,
+        stream: &mut MenuStream<'_, R, W>,
+        til: F,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        F: Fn(&T) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        self.prompt_until_async_with(stream, til, &self.fmt).await
+    }
+
+    /// Prompts the field and maps the parsed value with the `f` function, using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// After checking and parsing the value provided by the user, it calls the `f` function.
+    /// If it returns an error, the field is prompted again.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, or if it is rejected by the `f` function,
+    /// this function will panic.
+    pub fn prompt_map_with<R, W, T, U, E, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        f: F,
+        fmt: &Format<'a>,
+    ) -> MenuResult<U>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        F: Fn(T) -> Result<U, E>,
+    {
+        let parse = |s: &str| s.parse().ok().and_then(|out| f(out).ok());
+        self.parse_until_with(stream, parse, keep, fmt)
+    }
+
+    /// Prompts the field and maps the parsed value with the `f` function.
+    ///
+    /// After checking and parsing the value provided by the user, it calls the `f` function.
+    /// If it returns an error, the field is prompted again. It is useful to keep
+    /// the post-processing of the value close to the prompt, for instance to look up
+    /// the input in a table.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let users = [("ahmad", 1), ("bob", 2)];
+    /// let id = Written::from("User name").prompt_map(&mut MenuStream::default(), |name: String| {
+    ///     users
+    ///         .iter()
+    ///         .find(|(n, _)| *n == name)
+    ///         .map(|(_, id)| *id)
+    ///         .ok_or("unknown user")
+    /// })?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, or if it is rejected by the `f` function,
+    /// this function will panic.
+    pub fn prompt_map<R, W, T, U, E, F>(&self, stream: &mut MenuStream<R, W>, f: F) -> MenuResult<U>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+        F: Fn(T) -> Result<U, E>,
+    {
+        self.prompt_map_with(stream, f, &self.fmt)
+    }
+
+    /// Prompts the field, using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// It prompts the field until the value provided by the user is correct, then parses it.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_with<R, W, T>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'a>,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+    {
+        self.prompt_until_with(stream, keep, fmt)
+    }
+
+    /// Prompts the field.
+    ///
+    /// It prompts the field until the value provided by the user is correct, then parses it.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr,
+    {
+        self.prompt_with(stream, &self.fmt)
+    }
+
+    /// Prompts the field, parsing the input with the given function instead of
+    /// the [`FromStr`] implementation of the output type.
+    ///
+    /// It prompts the field until the `f` function successfully parses the value provided
+    /// by the user. This is useful for one-off formats, such as a hexadecimal color,
+    /// without wrapping the output type into a new type implementing `FromStr`.
+    /// The parsing errors are not returned, the field is prompted again instead.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let color = Written::from("Give a color")
+    ///     .example("#ff8000")
+    ///     .parse_with(&mut MenuStream::default(), |s| {
+    ///         u32::from_str_radix(s.trim_start_matches('#'), 16)
+    ///     })?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the default value cannot be parsed by `f`, this function will panic.
+    pub fn parse_with<R, W, T, E, F>(&self, stream: &mut MenuStream<R, W>, f: F) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        F: Fn(&str) -> Result<T, E>,
+    {
+        self.parse_until_with(stream, |s| f(s).ok(), keep, &self.fmt)
+    }
+
+    /// Prompts the field, parsing the input with the first of the given functions
+    /// that succeeds.
+    ///
+    /// It is like [`Written::parse_with`], but several formats are accepted, for instance
+    /// a date written as `2022-05-30` or as `05/30/2022`. The functions are tried in order,
+    /// and the field is prompted again only if all of them failed. In this case, the errors
+    /// of each function are displayed after the message of the format used when the input
+    /// is incorrect (see [`Format::invalid_msg`]).
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let parsers: [fn(&str) -> Result<u32, String>; 2] = [
+    ///     |s| s.parse().map_err(|e| format!("decimal: {}", e)),
+    ///     |s| {
+    ///         let hex = s.strip_prefix("0x").ok_or("hexadecimal: missing 0x prefix")?;
+    ///         u32::from_str_radix(hex, 16).map_err(|e| format!("hexadecimal: {}", e))
+    ///     },
+    /// ];
+    /// let addr = Written::from("Give an address").parse_any(&mut MenuStream::default(), &parsers)?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the default value cannot be parsed by any function, this function will panic.
+    pub fn parse_any<R, W, T, E, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        parsers: &[F],
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        E: Display,
+        F: Fn(&str) -> Result<T, E>,
+    {
+        self.parse_any_with(stream, parsers, &self.fmt, true)
+    }
+
+    /// Prompts the confirmation field, and returns the answer of the user.
+    ///
+    /// The input is accepted like a boolean field (see [`Written::boolean`]), or as the
+    /// quit token if it is provided (see [`Written::quit`]), case-insensitively.
+    /// It prompts the field until the user enters a correct answer.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Panic
+    ///
+    /// If the default value is not a correct answer, this function will panic.
+    pub fn confirm<R, W>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<Confirm>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        self.parse_any(
+            stream,
+            &[|s: &str| match self.quit {
+                Some(q) if s.trim().eq_ignore_ascii_case(q) => Ok(Confirm::Quit),
+                _ => parse_bool(s).map(Confirm::from).ok_or("expected yes or no"),
+            }],
+        )
+    }
+
+    /// Prompts the field, parsing the input with the first of the given functions
+    /// that succeeds, using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    /// If `bounded` is `true`, the input must be contained in the bounds of the field.
+    /// See [`Written::parse_any`] for more information.
+    fn parse_any_with<R, W, T, E, F>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        parsers: &[F],
+        fmt: &Format<'_>,
+        bounded: bool,
+    ) -> MenuResult<T>
+    where
+        R: BufRead,
+        W: Write,
+        E: Display,
+        F: Fn(&str) -> Result<T, E>,
+    {
+        let fmt = self.merged_fmt(fmt);
+
+        // The errors of the last parsed input.
+        let errors = RefCell::new(Vec::new());
+        let parse = |s: &str| {
+            let mut errors = errors.borrow_mut();
+            errors.clear();
+            for f in parsers {
+                match f(s) {
+                    Ok(out) => return Some(out),
+                    Err(e) => errors.push(e.to_string()),
+                }
+            }
+            None
+        };
+
+        let arg = self.arg_value(|s| match bounded {
+            true => self.parse_bounded_with(s, parse),
+            false => parse(s),
+        });
+        match arg {
+            Some(Some(out)) => return Ok(out),
+            Some(None) => self.show_invalid_input(&fmt, stream, true, &errors.take())?,
+            None => (),
+        }
+        self.first_line(stream, &fmt, false)?;
+
+        // Loops while incorrect input.
+        loop {
+            match self.prompt_once(stream, &fmt, false, bounded, parse)? {
+                Some(out) => return Ok(out),
+                None => self.show_invalid_input(&fmt, stream, false, &errors.take())?,
+            }
+        }
+    }
+
+    /// Prompts the field, parsing the input as a duration written with units,
+    /// such as `"1h30m"`, `"90s"` or `"2d"`.
+    ///
+    /// The available units are `ms`, `s`, `m`, `h` and `d`, matched case-sensitively.
+    /// The durations of all the numbers are added, and the whitespaces between them
+    /// are ignored, so `"1h 30m"` is also accepted.
+    ///
+    /// It prompts the field until the user enters a correct duration. If the format
+    /// has an [invalid message](Format::invalid_msg), an example of the expected format
+    /// is shown after it.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let timeout = Written::from("Timeout")
+    ///     .default_value("30s")
+    ///     .duration(&mut MenuStream::default())?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect format, this function will panic.
+    pub fn duration<R, W>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<Duration>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        self.parse_any(
+            stream,
+            &[|s: &str| parse_duration(s).ok_or("expected a duration, for example `1h30m`")],
+        )
+    }
+
+    /// Prompts the field, parsing the input as a number followed by a unit,
+    /// and returns the number multiplied by the factor of the unit.
+    ///
+    /// The units are given with their factor in the `units` table, for example
+    /// `[("KB", 1_000), ("MB", 1_000_000)]`. The unit is matched case-sensitively, and
+    /// the longest matching unit is used. The whitespaces between the number and the unit
+    /// are ignored, and a number without any unit is returned as is.
+    ///
+    /// It prompts the field until the user enters a correct value.
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let bytes = Written::from("Size of the cache")
+    ///     .example("10MB")
+    ///     .with_unit(
+    ///         &mut MenuStream::default(),
+    ///         &[("B", 1), ("KB", 1_000), ("MB", 1_000_000), ("GB", 1_000_000_000)],
+    ///     )?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the default value has an incorrect format, this function will panic.
+    pub fn with_unit<R, W>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        units: &[(&str, u64)],
+    ) -> MenuResult<u64>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        self.parse_with(stream, |s| parse_unit(s, units).ok_or(MenuError::Input))
+    }
+
+    /// Prompts the field and returns the input value, or the default value of the type
+    /// if the input is incorrect, using the given format.
+    ///
+    /// It uses the merged version between the format of the written field and the given format.
+    ///
+    /// It prompts the value once, and if the user entered a correct input, it returns the value,
+    /// otherwise, it attempts to return the default value (see [`Written::default_value`]
+    /// or [`Written::default_env`]), and if there is no default value, it returns
+    /// the [default](Default) implementation of `T`.
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_or_default_with<R, W, T>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+        fmt: &Format<'_>,
+    ) -> T
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr + Default,
+    {
+        self.optional_value_with(stream, fmt)
+            .map(Option::unwrap_or_default)
+            .unwrap_or_default()
+    }
+
+    /// Prompts the field and returns the input value, or the default value of the type
+    /// if the input is incorrect.
+    ///
+    /// It prompts the value once, and if the user entered a correct input, it returns the value,
+    /// otherwise, it attempts to return the default value (see [`Written::default_value`]
+    /// or [`Written::default_env`]), and if there is no default value, it returns
+    /// the [default](Default) implementation of `T`.
+    ///
+    /// # Panics
+    ///
+    /// If the default value has an incorrect type, this function will panic.
+    pub fn prompt_or_default<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> T
+    where
+        R: BufRead,
+        W: Write,
+        T: FromStr + Default,
+    {
+        self.prompt_or_default_with(stream, &self.fmt)
+    }
+}
+
+/// Used to define a selectable type.
+///
+/// It provides the fields, corresponding to a message and the return value.
+/// It is used by the [`Selected`] struct with its `From<&str>` implementation,
+/// or with [`Selected::from_selectable`], to list the values of the type.
+///
+/// The [`Selectable::default`] method provides the default index, and is unrelated
+/// to the [`Default`] trait. To use the value of the [`Default`] implementation instead,
+/// see [`Selected::default_of_type`].
+///
+/// The `N` const generic parameter represents the amount of available selectable values.
+///
+/// # Example
+///
+/// ```
+/// # use ezmenulib::field::Selectable;
+/// enum Type {
+///     MIT,
+///     GPL,
+///     BSD,
+/// }
+///
+/// impl Selectable<3> for Type {
+///     fn values() -> [(&'static str, Self); 3] {
+///         [
+///             ("MIT", Self::MIT),
+///             ("GPL", Self::GPL),
+///             ("BSD", Self::BSD),
+///         ]
+///     }
+/// }
+/// ```
+pub trait Selectable<const N: usize>: Sized {
+    /// Provides the fields, corresponding to a message and the return value.
+    fn values() -> [(&'static str, Self); N];
+
+    /// Provides the default fields, by its index, if it is available.
+    fn default() -> Option<usize> {
+        None
+    }
+}
+
+/// Defines the behavior for a selected value provided by the user.
+///
+/// Like the [written](Written) values, it contains its own [format](Format),
+/// and it can be inherited, saving the custom format specifications.
+///
+/// It displays the message with the available fields to select, with the
+/// default field marked as "(default)" if it is provided (see [`Selected::default`] function).
+/// You have to provide a mutable reference to a [`MenuStream`] to retrieve the selected value.
+///
+/// You can use beside it the [`Selectable`] trait to list the available values to select.
+///
+/// The `N` const generic parameter represents the amount of available selectable values.
+///
+/// # Example
+///
+/// For a make-license CLI program for example, you can use it like below:
+///
+/// ```no_run
+/// use ezmenulib::prelude::*;
+///
+/// enum Type {
+///     MIT,
+///     GPL,
+///     BSD,
+/// }
+///
+/// impl Selectable<3> for Type {
+///     fn values() -> [(&'static str, Self); 3] {
+///         use Type::*;
+///         [
+///             ("MIT", MIT),
+///             ("GPL", GPL),
+///             ("BSD", BSD),
+///         ]
+///     }
+/// }
+///
+/// let s: Type = Selected::from("Select the license type")
+///     .select(&mut MenuStream::default())
+///     .unwrap();
+/// ```
+// Clone is implemented on it because it is moved once the user selected the value.
+#[derive(Debug, Clone)]
+pub struct Selected<'a, T, const N: usize> {
+    /// The format used by the selected field value.
+    pub fmt: Format<'a>,
+    msg: Cow<'a, str>,
+    fields: [(&'a str, T); N],
+    default: Option<usize>,
+    attempts: Option<usize>,
+    details: Option<[&'a str; N]>,
+    countdown: Option<Duration>,
+    groups: Vec<(usize, &'a str)>,
+    visible: Vec<usize>,
+    many_tokens: (&'a str, &'a str),
+    mnemonics: Option<bool>,
+    io_retries: usize,
+    echo_choice: bool,
+}
+
+/// Builds the selectable field from its message, with the values and the default index
+/// provided by the [`Selectable`] implementation of the output type.
+///
+/// The output type is usually inferred from the type of the selected value,
+/// so `Selected::from("License")` lists the values of the expected type.
+/// See [`Selected::from_selectable`] for an explicit version.
+impl<'a, T, const N: usize> From<&'a str> for Selected<'a, T, N>
+where
+    T: Selectable<N>,
+{
+    fn from(msg: &'a str) -> Self {
+        Self::inner_new(Cow::Borrowed(msg), T::values(), T::default())
+    }
+}
+
+/// Builds the selectable field from an owned message, for instance a message
+/// formatted at runtime, like the `From<&str>` implementation.
+impl<T, const N: usize> From<String> for Selected<'_, T, N>
+where
+    T: Selectable<N>,
+{
+    fn from(msg: String) -> Self {
+        Self::inner_new(Cow::Owned(msg), T::values(), T::default())
+    }
+}
+
+impl<'a, T, const N: usize> Selected<'a, T, N>
+where
+    T: Selectable<N>,
+{
+    /// Returns the selectable field with the given message, listing the values provided
+    /// by the [`Selectable`] implementation of `T`, with its default index if any.
+    ///
+    /// It is the explicit version of the `From<&str>` implementation, useful when
+    /// the output type is not inferred from the selected value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// #[derive(Debug)]
+    /// enum License {
+    ///     Mit,
+    ///     Gpl,
+    /// }
+    ///
+    /// impl Selectable<2> for License {
+    ///     fn values() -> [(&'static str, Self); 2] {
+    ///         [("MIT", Self::Mit), ("GPL", Self::Gpl)]
+    ///     }
+    /// }
+    ///
+    /// let license = Selected::<License, 2>::from_selectable("License")
+    ///     .select(&mut MenuStream::default())
+    ///     .unwrap();
+    /// println!("{:?}", license);
+    /// ```
+    pub fn from_selectable<M: Into<Cow<'a, str>>>(msg: M) -> Self {
+        Self::inner_new(msg.into(), T::values(), T::default())
+    }
+}
+
+impl<'a, T, const N: usize> Selected<'a, T, N> {
+    fn inner_new(msg: Cow<'a, str>, fields: [(&'a str, T); N], default: Option<usize>) -> Self {
+        Self {
+            fmt: Default::default(),
+            msg,
+            fields,
+            default,
+            attempts: None,
+            details: None,
+            countdown: None,
+            groups: Vec::new(),
+            visible: (0..N).collect(),
+            many_tokens: ("*", "0"),
+            mnemonics: None,
+            io_retries: 0,
+            echo_choice: false,
+        }
+    }
+
+    /// Returns the Selected wrapper using the given message and
+    /// selectable fields.
+    ///
+    /// # Note
+    ///
+    /// If `T` implements `Selectable`, you may use the `From<&str>` implementation
+    /// for `Selected`, to not write again the available selectable fields.
+    ///
+    /// If the fields array is empty, selecting a value returns
+    /// a [`MenuError::EmptyMenu`] error.
+    pub fn new<M: Into<Cow<'a, str>>>(msg: M, fields: [(&'a str, T); N]) -> Self {
+        Self::inner_new(msg.into(), fields, None)
+    }
+
+    /// Gives a custom formatting for the selected value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::field::{Selected, Format, Selectable};
+    /// enum Type {
+    ///     MIT,
+    ///     GPL,
+    ///     BSD,
+    /// }
+    ///
+    /// let w = Selected::new("Select the license type", [
+    ///     ("MIT", Type::MIT),
+    ///     ("GPL", Type::GPL),
+    ///     ("BSD", Type::BSD),
+    /// ])
+    /// .format(Format::prefix("==> "));
+    /// ```
+    pub fn format(mut self, fmt: Format<'a>) -> Self {
+        self.fmt = fmt;
+        // Saves the default suffix if asked to break the line,
+        // because it would be ugly to have for instance ": " as suffix.
+        // This is useful if the format is inherited (see [`Values::selected`] function).
+        if !self.fmt.line_brk {
+            self.fmt.suffix = DEFAULT_FMT.suffix;
+        }
+        self
+    }
+
+    /// Defines the default value among the the selectable values, by its index.
+    ///
+    /// The default value is selected if the user enters an incorrect index,
+    /// or if the input stream reached its end (for instance in a scripted run).
+    ///
+    /// # Note
+    ///
+    /// If the index is out of bounds, it will not panic at runtime. Therefore,
+    /// if the user enters an incorrect index, it will not use the default index.
+    pub fn default(mut self, default: usize) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Removes the default value of the field, if any was provided.
+    ///
+    /// The selection becomes required again: the field is no longer marked as optional,
+    /// and an empty or incorrect input counts as a failed attempt.
+    /// It is useful when deriving a field from a base configuration with a default value.
+    pub fn no_default(mut self) -> Self {
+        self.default = None;
+        self
+    }
+
+    /// Defines the default value as the value equal to the [`Default`] implementation of `T`.
+    ///
+    /// It keeps the default index of the field in sync with the default value of the type,
+    /// for instance when `T` implements both [`Selectable`] and [`Default`].
+    /// If no value is equal to it, the default index is not changed.
+    pub fn default_of_type(self) -> Self
+    where
+        T: Default + PartialEq,
+    {
+        let default = T::default();
+        self.default_by(|v| *v == default)
+    }
+
+    /// Defines the default value as the first value matching the given predicate.
+    ///
+    /// It is useful when the default value is defined by a property of the values,
+    /// for instance a key, rather than by its index or by the equality of the values.
+    /// If no value matches the predicate, the default index is not changed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let port: (&str, u16) = Selected::new(
+    ///     "Select the service",
+    ///     [("HTTP", ("http", 80)), ("HTTPS", ("https", 443))],
+    /// )
+    /// .default_by(|(name, _)| *name == "https")
+    /// .select(&mut MenuStream::default())
+    /// .unwrap();
+    /// ```
+    pub fn default_by<F: Fn(&T) -> bool>(mut self, predicate: F) -> Self {
+        if let Some(i) = self.fields.iter().position(|(_, v)| predicate(v)) {
+            self.default = Some(i);
+        }
+        self
+    }
+
+    /// Defines the maximum amount of attempts the user has to select a value.
+    ///
+    /// An attempt corresponds to an empty or incorrect input. When the user reaches
+    /// the maximum amount of attempts, the [`Selected::select`] method returns
+    /// a [`MenuError::NoSelection`] error, instead of prompting the suffix again.
+    /// If a default value is provided, the user never fails an attempt.
+    pub fn attempts(mut self, attempts: usize) -> Self {
+        self.attempts = Some(attempts);
+        self
+    }
+
+    /// Defines the amount of times the values are displayed and prompted again
+    /// when writing to or reading from the stream fails with an IO error (`0` by default).
+    ///
+    /// It is useful in unreliable terminal environments, where the IO errors may be
+    /// transient. The retries are distinct from the [attempts](Selected::attempts)
+    /// of the user. Once the retries are exhausted, the IO error is returned.
+    pub fn io_retries(mut self, retries: usize) -> Self {
+        self.io_retries = retries;
+        self
+    }
+
+    /// Writes the label of the selected value, as `"--> Selected: <label>"`, once the user
+    /// selected a value (disabled by default).
+    ///
+    /// It confirms which value the input resolved to, for instance when the user
+    /// typed a label or a mnemonic instead of an index. The label is written after
+    /// the prefix and the `echo_msg` format specification. It is not written if the format
+    /// is quiet.
+    pub fn echo_choice(mut self) -> Self {
+        self.echo_choice = true;
+        self
+    }
+
+    /// Displays the selectable values to the stream, in the normal or the alternate form,
+    /// retrying on IO errors (see [`Selected::io_retries`]).
+    fn show<W: Write>(&self, stream: &mut W, alternate: bool) -> MenuResult {
+        if self.fmt.quiet {
+            return Ok(());
+        }
+        retry_io(self.io_retries, || match alternate {
+            true => Ok(write!(stream, "{:#}", self)?),
+            false => Ok(write!(stream, "{}", self)?),
+        })
+    }
+
+    /// Returns the message of the field.
+    pub(crate) fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    /// Returns `true` if the field has a correct default index.
+    pub(crate) fn has_default(&self) -> bool {
+        self.default_pos().is_some()
+    }
+
+    /// Returns the position of the default value among the displayed values,
+    /// if it is provided and not hidden by the filter.
+    fn default_pos(&self) -> Option<usize> {
+        let default = self.default?;
+        self.visible.iter().position(|i| *i == default)
+    }
+
+    /// Hides the selectable values that do not satisfy the given predicate.
+    ///
+    /// The hidden values are not displayed, and the remaining values are numbered
+    /// from `1`, so the selected value is guaranteed to satisfy the predicate.
+    /// The indexes given to the other methods, such as [`Selected::default`]
+    /// or [`Selected::group`], still correspond to the original order of the values.
+    /// If the default value is hidden, the field has no default value.
+    /// If all the values are hidden, selecting a value returns a [`MenuError::EmptyMenu`] error.
+    ///
+    /// The predicates are combined if this method is called several times.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let copyleft = true;
+    /// let license: &str = Selected::new("License", [("MIT", "mit"), ("BSD", "bsd"), ("GPL", "gpl")])
+    ///     .filter(|l| !copyleft || *l == "gpl")
+    ///     .select(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn filter<F: Fn(&T) -> bool>(mut self, f: F) -> Self {
+        let fields = &self.fields;
+        self.visible.retain(|i| f(&fields[*i].1));
+        self
+    }
+
+    /// Sorts the displayed values with the given comparison function, applied to the
+    /// label and the value of the fields.
+    ///
+    /// The values are sorted before being displayed, without changing the order they
+    /// were given in, so each displayed index still selects its own value. The sort is
+    /// stable, and the hidden values (see [`Selected::filter`]) stay hidden.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let license: &str = Selected::new("License", [("MIT", "mit"), ("GPL", "gpl"), ("BSD", "bsd")])
+    ///     .sorted_by(|(a, _), (b, _)| a.cmp(b))
+    ///     .select(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn sorted_by<F>(mut self, compare: F) -> Self
+    where
+        F: Fn(&(&'a str, T), &(&'a str, T)) -> Ordering,
+    {
+        let fields = &self.fields;
+        self.visible
+            .sort_by(|a, b| compare(&fields[*a], &fields[*b]));
+        self
+    }
+
+    /// Allows the user to select a value by entering the first letter of its label,
+    /// like the mnemonics of a menu.
+    ///
+    /// The letters are matched case-insensitively. If several displayed values have
+    /// labels starting with the same letter, they can only be selected with their
+    /// index or their label. If `underline` is `true`, the mnemonic of each label
+    /// is underlined when displaying the values, using ANSI escape codes.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// // The user can enter "y" or "n".
+    /// let confirm: bool = Selected::new("Confirm", [("Yes", true), ("No", false)])
+    ///     .mnemonics(true)
+    ///     .select(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn mnemonics(mut self, underline: bool) -> Self {
+        self.mnemonics = Some(underline);
+        self
+    }
+
+    /// Returns the mnemonics of the displayed values, if enabled (see [`Selected::mnemonics`]).
+    ///
+    /// The mnemonic of a value is the lowercased first letter of its label,
+    /// unless the label of another displayed value starts with the same letter.
+    fn mnemonic_keys(&self) -> Vec<Option<char>> {
+        if self.mnemonics.is_none() {
+            return Vec::new();
+        }
+        let first = |label: &str| {
+            let c = label.trim_start().chars().next()?;
+            c.is_alphabetic().then(|| c.to_lowercase().next()).flatten()
+        };
+        let firsts: Vec<Option<char>> = self.labels().into_iter().map(first).collect();
+        firsts
+            .iter()
+            .map(|c| c.filter(|c| firsts.iter().filter(|d| **d == Some(*c)).count() == 1))
+            .collect()
+    }
+
+    /// Checks that the selectable values are consistent, without prompting the field.
+    ///
+    /// It returns a [`MenuError::EmptyMenu`] error if there is no value to select,
+    /// for instance because all of them have been filtered out (see [`Selected::filter`]).
+    /// It returns a [`MenuError::Other`] error if the default index is out of bounds,
+    /// or if two values have the same label, which makes the selection by label ambiguous.
+    pub fn validate(&self) -> MenuResult {
+        check_fields(&self.visible)?;
+        if let Some(i) = self.default.filter(|i| *i >= N) {
+            return Err(MenuError::from(format!(
+                "the default index {} is out of bounds for {} values",
+                i, N
+            )));
+        }
+
+        for (i, (label, _)) in self.fields.iter().enumerate() {
+            let duplicate = self.fields[i + 1..]
+                .iter()
+                .any(|(other, _)| other.eq_ignore_ascii_case(label));
+            if duplicate {
+                return Err(MenuError::from(format!(
+                    "the label `{}` is used by many values",
+                    label
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Defines the details of the selectable values, in the same order as the values.
+    ///
+    /// The details are not displayed in the list, but the user can display the detail
+    /// of a value by entering its index or its label prefixed with `'?'`, for instance `"?2"`,
+    /// before selecting it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let install: u8 = Selected::new("Installation", [("Minimal", 0), ("Full", 1)])
+    ///     .details([
+    ///         "Installs only the required components.",
+    ///         "Installs all the components, with the documentation.",
+    ///     ])
+    ///     .select(&mut MenuStream::default())
+    ///     .unwrap();
+    /// ```
+    pub fn details(mut self, details: [&'a str; N]) -> Self {
+        self.details = Some(details);
+        self
+    }
+
+    /// Inserts a group header right before the selectable value at the given index.
+    ///
+    /// The header is displayed without any index, so the numbering of the values
+    /// is not modified, and it cannot be selected by the user.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let license = Selected::new("License", [("MIT", 0), ("BSD", 1), ("GPL", 2)])
+    ///     .group(0, "-- Permissive --")
+    ///     .group(2, "-- Copyleft --");
+    /// ```
+    pub fn group(mut self, at: usize, header: &'a str) -> Self {
+        self.groups.push((at, header));
+        self
+    }
+
+    /// Defines the duration of the countdown before the default value is selected,
+    /// like a boot menu.
+    ///
+    /// The remaining seconds are displayed in place, and the countdown is canceled
+    /// when the user presses any key, then the value is selected normally.
+    /// The key pressed to cancel it is not part of the user input.
+    ///
+    /// The countdown runs only if a default value is provided (see [`Selected::default`]),
+    /// with the `crossterm` feature, and if the standard input is a terminal,
+    /// because the keypresses are detected in raw mode. Otherwise, it has no effect.
+    pub fn countdown(mut self, duration: Duration) -> Self {
+        self.countdown = Some(duration);
+        self
+    }
+
+    /// Runs the countdown if it is provided, and returns the default index
+    /// if it elapsed without any keypress.
+    fn run_countdown<W: Write>(&self, stream: &mut W) -> MenuResult<Option<usize>> {
+        match (self.countdown, self.default) {
+            (Some(duration), Some(i)) if self.has_default() && !self.fmt.quiet => {
+                let elapsed = countdown(stream, &self.fmt, duration)?;
+                Ok(if elapsed { Some(i) } else { None })
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Writes the label of the selected value at index `i`, if the field echoes the choice
+    /// (see [`Selected::echo_choice`]), and returns the index.
+    fn echo<W: Write>(&self, stream: &mut W, i: usize) -> MenuResult<usize> {
+        if self.echo_choice && !self.fmt.quiet {
+            write!(
+                stream,
+                "{}{}{}{}",
+                self.fmt.prefix,
+                self.fmt.echo_msg,
+                self.fields[i].0.trim(),
+                self.fmt.line_ending
+            )?;
+        }
+        Ok(i)
+    }
+
+    /// Prompts the selectable fields once.
+    ///
+    /// In fact, it only displays the suffix, and gets the user input, then returns
+    /// the correct index wrapped in a `Query`.
+    fn prompt_once<R: BufRead, W: Write>(
+        &self,
+        stream: &mut MenuStream<R, W>,
+    ) -> MenuResult<Option<usize>> {
+        let details: Vec<&str> = match self.details {
+            Some(ref d) => self.visible.iter().map(|i| d[*i]).collect(),
+            None => Vec::new(),
+        };
+        let selected = select(
+            stream,
+            &self.fmt,
+            &self.labels(),
+            &details,
+            &self.mnemonic_keys(),
+            self.default_pos(),
+        )?;
+        Ok(selected.map(|pos| self.visible[pos]))
+    }
+
+    /// Returns the labels of the displayed values.
+    fn labels(&self) -> Vec<&'a str> {
+        self.visible.iter().map(|i| self.fields[*i].0).collect()
+    }
+
+    /// Prompts the selectable fields and returns the value at the input index,
+    /// or `None` if the index is incorrect.
+    ///
+    /// It prompts the selectable fields once, and if the user entered a correct index,
+    /// it returns `Some(value)` where `value` corresponds to the value mapped to this index,
+    /// otherwise, it attempts to return the value mapped by the default index
+    /// (see `Selected::default`), and if there is no default index, it returns `None`.
+    ///
+    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
+    pub fn optional_select<R, W>(self, stream: &mut MenuStream<R, W>) -> MenuResult<Option<T>>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        check_fields(&self.visible)?;
+        // Uses the alternate form of selection field display
+        // to display the "(optional)" string slice message.
+        self.show(stream, true)?;
+        let selected = match self.run_countdown(stream)? {
+            Some(i) => Some(i),
+            None => retry_io(self.io_retries, || self.prompt_once(stream))?,
+        };
+        let selected = selected.map(|i| self.echo(stream, i)).transpose()?;
+
+        Ok(selected.map(|i| {
+            // SAFETY: the `Selected::run_countdown` and `Selected::prompt_once` functions
+            // guarantee that the index is in bounds.
+            unsafe { self.take(i) }
+        }))
+    }
+
+    /// Gives the value stored at index `i`, consuming `self`.
+    ///
+    /// The index must be in bounds, or this will cause an undefined behavior.
+    ///
+    /// # Safety
+    ///
+    /// The `i` index must be in bounds, meaning `i < N`.
+    /// Otherwise, this function results in an undefined behavior.
+    unsafe fn take(self, i: usize) -> T {
+        self.take_field(i).1
+    }
+
+    /// Gives the message and the value stored at index `i`, consuming `self`.
+    ///
+    /// # Safety
     ///
-    /// # Panics
+    /// See [`Selected::take`].
+    unsafe fn take_field(self, i: usize) -> (&'a str, T) {
+        self.fields.into_iter().nth(i).unwrap_unchecked()
+    }
+
+    /// Prompts the selectable values to the user until a value is selected,
+    /// and returns its index.
     ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn optional_value<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<Option<T>>
+    /// The returned index is guaranteed to be in bounds.
+    fn select_index<R, W>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<usize>
     where
         R: BufRead,
         W: Write,
-        T: FromStr,
     {
-        self.optional_value_with(stream, &self.fmt)
+        // Without a back key, the user cannot leave the selection.
+        Ok(self.select_index_or_back(stream, None)?.unwrap_or_default())
     }
 
-    /// Prompts the field and returns the inputs as a `Vec<T>` until the given
-    /// constraint is applied to all the values, using `sep` to split the input
-    /// into the output values, and using the given format.
-    ///
-    /// It uses the merged version between the format of the written field and the given format.
-    ///
-    /// After checking and parsing the values provided by the user, it calls the `til` function.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// # Panics
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn many_values_until_with<R, W, T, S, F>(
+    /// Prompts the selectable values to the user until a value is selected,
+    /// and returns its index, or `None` if the user entered the given back key.
+    fn select_index_or_back<R, W>(
         &self,
         stream: &mut MenuStream<R, W>,
-        sep: S,
-        til: F,
-        fmt: &Format<'_>,
-    ) -> MenuResult<Vec<T>>
+        back_key: Option<&str>,
+    ) -> MenuResult<Option<usize>>
     where
         R: BufRead,
         W: Write,
-        T: FromStr,
-        S: AsRef<str>,
-        F: Fn(&T) -> bool,
     {
-        fn inner_prompt_once<R: BufRead, W: Write, T: FromStr>(
-            w: &Written<'_>,
-            stream: &mut MenuStream<R, W>,
-            sep: &str,
-            fmt: &Format<'_>,
-        ) -> MenuResult<Option<Vec<T>>> {
-            let s = w.prompt_line(stream, fmt, false)?;
-            let res: Result<Vec<T>, T::Err> = s.split(sep).map(T::from_str).collect();
-
-            Ok(res.ok().or_else(|| {
-                let default = w.default.as_ref()?;
-                let res: Result<Vec<T>, T::Err> = default.split(sep).map(T::from_str).collect();
-                Some(res.unwrap_or_else(|_| default_failed::<T>(default)))
-            }))
+        check_fields(&self.visible)?;
+        self.show(stream, false)?;
+        if let Some(i) = self.run_countdown(stream)? {
+            return self.echo(stream, i).map(Some);
         }
-
-        let fmt = self.fmt.merged(fmt);
-        self.first_line(stream, &fmt, false)?;
-        let s = sep.as_ref();
-
-        // Loops while incorrect input.
+        let mut attempts = 0;
         loop {
-            match inner_prompt_once(self, stream, s, &fmt)? {
-                Some(v) if v.iter().all(&til) => return Ok(v),
-                _ => continue,
+            let selected = retry_io(self.io_retries, || self.prompt_once(stream))?;
+            // The back key is checked first, so it is not replaced by the default value.
+            if back_key.is_some() && stream.last_input() == back_key {
+                stream.take_last_input();
+                return Ok(None);
+            }
+            match selected {
+                Some(i) => return self.echo(stream, i).map(Some),
+                None => {
+                    attempts += 1;
+                    if matches!(self.attempts, Some(max) if attempts >= max) {
+                        return Err(MenuError::NoSelection);
+                    }
+                    show_invalid(&self.fmt, stream)?;
+                }
             }
         }
     }
 
-    /// Prompts the field and returns the inputs as a `Vec<T>` until the given
-    /// constraint is applied to all the values, using `sep` to split the input
-    /// into the output values.
+    /// Prompts the selectable values to the user.
     ///
-    /// After checking and parsing the values provided by the user, it calls the `til` function.
+    /// It prompts the fields once and the suffix until the index provided, then returns the selected value.
     /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
     ///
-    /// # Panics
+    /// If the input stream reached its end, it returns the default value if it is provided
+    /// (see [`Selected::default`]), otherwise it returns a [`MenuError::Eof`] error.
+    /// If a maximum amount of attempts is provided (see [`Selected::attempts`]),
+    /// it returns a [`MenuError::NoSelection`] error once the user reached it.
     ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn many_values_until<R, W, T, S, F>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        sep: S,
-        til: F,
-    ) -> MenuResult<Vec<T>>
+    /// This function consumes `self` because it returns the ownership of a contained value
+    /// (`T`) defined earlier in the [`Selected::new`] function.
+    pub fn select<R, W>(self, stream: &mut MenuStream<R, W>) -> MenuResult<T>
     where
         R: BufRead,
         W: Write,
-        T: FromStr,
-        S: AsRef<str>,
-        F: Fn(&T) -> bool,
     {
-        self.many_values_until_with(stream, sep, til, &self.fmt)
+        let i = self.select_index(stream)?;
+        // SAFETY: the `Selected::select_index` guarantees that the index is in bounds.
+        Ok(unsafe { self.take(i) })
     }
 
-    /// Prompts the field and returns the inputs as a `Vec<T>` using `sep` to split the input
-    /// into the output values, and using the given format.
-    ///
-    /// It uses the merged version between the format of the written field and the given format.
-    ///
-    /// After checking and parsing the values provided by the user, it calls the `til` function.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    /// Prompts the selectable values to the user, and returns the message of the selected
+    /// field with its value.
     ///
-    /// # Panics
+    /// It is useful to display or log the label picked by the user, for instance
+    /// `"User chose MIT"`, without looking it up from the value.
     ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn many_values_with<R, W, T, S>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        sep: S,
-        fmt: &Format<'_>,
-    ) -> MenuResult<Vec<T>>
+    /// See [`Selected::select`] for more information.
+    pub fn select_labeled<R, W>(self, stream: &mut MenuStream<R, W>) -> MenuResult<(&'a str, T)>
     where
         R: BufRead,
         W: Write,
-        T: FromStr,
-        S: AsRef<str>,
     {
-        self.many_values_until_with(stream, sep, keep, fmt)
+        let i = self.select_index(stream)?;
+        // SAFETY: the `Selected::select_index` guarantees that the index is in bounds.
+        Ok(unsafe { self.take_field(i) })
     }
 
-    /// Prompts the field and returns the inputs as a `Vec<T>` using `sep` to split the input
-    /// into the output values.
+    /// Prompts the selectable values to the user, and returns the selected [choice](Choice),
+    /// with its label, its index and its detail, if any, with its value.
     ///
-    /// After checking and parsing the values provided by the user, it calls the `til` function.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    /// See [`Selected::select`] for more information.
     ///
-    /// # Panics
+    /// # Example
     ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn many_values<R, W, T, S>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        sep: S,
-    ) -> MenuResult<Vec<T>>
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let choice = Selected::new("Plugin", [("Formatter", 0), ("Linter", 1)])
+    ///     .details(["Formats the code.", "Checks the code."])
+    ///     .select_choice(&mut MenuStream::default())
+    ///     .unwrap();
+    /// println!("loading {} ({})", choice.label, choice.detail.unwrap_or_default());
+    /// ```
+    pub fn select_choice<R, W>(self, stream: &mut MenuStream<R, W>) -> MenuResult<Choice<'a, T>>
     where
         R: BufRead,
         W: Write,
-        T: FromStr,
-        S: AsRef<str>,
     {
-        self.many_values_with(stream, sep, &self.fmt)
+        let index = self.select_index(stream)?;
+        let detail = self.details.map(|d| d[index]);
+        // SAFETY: the `Selected::select_index` guarantees that the index is in bounds.
+        let (label, value) = unsafe { self.take_field(index) };
+        Ok(Choice {
+            label,
+            index,
+            detail,
+            value,
+        })
     }
 
-    /// Prompts the field until the constraint is applied, using the given format.
+    /// Prompts the selectable values to the user, and returns a clone of the selected value.
     ///
-    /// It uses the merged version between the format of the written field and the given format.
-    /// After checking and parsing the value provided by the user, it calls the `til` function.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
+    /// Unlike [`Selected::select`], it does not consume the field, so the same field
+    /// can be prompted several times, for instance in a loop.
     ///
-    /// # Panic
+    /// # Example
     ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt_until_with<R, W, T, F>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        til: F,
-        fmt: &Format<'a>,
-    ) -> MenuResult<T>
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let mut stream = MenuStream::default();
+    /// let action = Selected::new("Action", [("Continue", true), ("Stop", false)]);
+    /// while action.select_cloned(&mut stream)? {
+    ///     // ...
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn select_cloned<R, W>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<T>
     where
         R: BufRead,
         W: Write,
-        T: FromStr,
-        F: Fn(&T) -> bool,
+        T: Clone,
     {
-        let fmt = self.fmt.merged(fmt);
-        self.first_line(stream, &fmt, false)?;
+        let i = self.select_index(stream)?;
+        Ok(self.fields[i].1.clone())
+    }
 
-        // Loops while incorrect input.
-        loop {
-            match self.prompt_once(stream, &fmt, false)? {
-                Some(out) if til(&out) => return Ok(out),
-                _ => continue,
-            }
-        }
+    /// Defines the tokens the user enters to select all the values, or none of them,
+    /// when selecting many values (`"*"` and `"0"` by default).
+    ///
+    /// See [`Selected::select_many`] for more information.
+    pub fn many_tokens(mut self, all: &'a str, none: &'a str) -> Self {
+        self.many_tokens = (all, none);
+        self
     }
 
-    /// Prompts the field until the constraint is applied.
+    /// Prompts the selectable values to the user, and returns the values selected
+    /// by their indexes or labels, separated by `sep`.
     ///
-    /// After checking and parsing the value provided by the user, it calls the `til` function.
+    /// The user can select all the values, or none of them, by entering the corresponding
+    /// token (see [`Selected::many_tokens`]). An empty input also selects none of them.
+    /// The values are returned in their original order, and each value is returned once.
+    /// If an index or a label is incorrect, the user is prompted again.
     /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
     ///
-    /// # Panic
+    /// # Example
     ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt_until<R, W, T, F>(&self, stream: &mut MenuStream<R, W>, til: F) -> MenuResult<T>
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let features: Vec<&str> = Selected::new("Features", [
+    ///     ("Logging", "log"),
+    ///     ("Metrics", "metrics"),
+    ///     ("Tracing", "tracing"),
+    /// ])
+    /// .select_many(&mut MenuStream::default(), ",")
+    /// .unwrap();
+    /// ```
+    pub fn select_many<R, W>(self, stream: &mut MenuStream<R, W>, sep: &str) -> MenuResult<Vec<T>>
     where
         R: BufRead,
         W: Write,
-        T: FromStr,
-        F: Fn(&T) -> bool,
     {
-        self.prompt_until_with(stream, til, &self.fmt)
+        check_fields(&self.visible)?;
+        self.show(stream, false)?;
+
+        let labels = self.labels();
+        let (all, none) = self.many_tokens;
+        let selected = loop {
+            let s = retry_io(self.io_retries, || prompt(&self.fmt, stream))?;
+            let s = s.trim();
+            if s == all {
+                break self.visible.clone();
+            }
+            if s.is_empty() || s == none {
+                break Vec::new();
+            }
+
+            let selected: Option<Vec<usize>> = s
+                .split(sep)
+                .map(|s| parse_selection(s, &labels, &self.fmt))
+                .map(|pos| pos.map(|pos| self.visible[pos]))
+                .collect();
+            match selected {
+                Some(selected) => break selected,
+                None => show_invalid(&self.fmt, stream)?,
+            }
+        };
+
+        Ok(self
+            .fields
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| selected.contains(i))
+            .map(|(_, (_, out))| out)
+            .collect())
     }
 
-    /// Prompts the field, using the given format.
+    /// Prompts the selectable values to the user, and returns all the values whose label
+    /// starts with the prefix entered by the user.
     ///
-    /// It uses the merged version between the format of the written field and the given format.
-    /// It prompts the field until the value provided by the user is correct, then parses it.
+    /// Each time the user enters a prefix, the labels matching it, ignoring the ASCII case,
+    /// are displayed, and the user is prompted again to refine the query. An empty input
+    /// confirms the query, and selects all the values currently matching it, or all the
+    /// values if no prefix has been entered yet. If no label matches a prefix, the user
+    /// is prompted again with the previous query.
+    /// The values are returned in their original order.
     /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
     ///
-    /// # Panic
+    /// # Example
     ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt_with<R, W, T>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        fmt: &Format<'a>,
-    ) -> MenuResult<T>
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// let licenses: Vec<&str> = Selected::new("Licenses", [
+    ///     ("MIT", "mit"),
+    ///     ("GPL-2.0", "gpl2"),
+    ///     ("GPL-3.0", "gpl3"),
+    /// ])
+    /// .select_matching(&mut MenuStream::default())
+    /// .unwrap();
+    /// ```
+    pub fn select_matching<R, W>(self, stream: &mut MenuStream<R, W>) -> MenuResult<Vec<T>>
     where
         R: BufRead,
         W: Write,
-        T: FromStr,
     {
-        self.prompt_until_with(stream, keep, fmt)
+        check_fields(&self.visible)?;
+        self.show(stream, false)?;
+
+        let mut selected = self.visible.clone();
+        loop {
+            let s = retry_io(self.io_retries, || prompt(&self.fmt, stream))?;
+            let query = s.trim().to_ascii_lowercase();
+            if query.is_empty() {
+                break;
+            }
+
+            let matching: Vec<usize> = self
+                .visible
+                .iter()
+                .copied()
+                .filter(|i| {
+                    let label = self.fields[*i].0.trim_start();
+                    label.to_ascii_lowercase().starts_with(&query)
+                })
+                .collect();
+            if matching.is_empty() {
+                show_invalid(&self.fmt, stream)?;
+                continue;
+            }
+            if !self.fmt.quiet {
+                for i in &matching {
+                    write!(
+                        stream,
+                        "{}{}{}",
+                        self.fmt.chip.trim_start(),
+                        self.fields[*i].0,
+                        self.fmt.line_ending
+                    )?;
+                }
+            }
+            selected = matching;
+        }
+
+        Ok(self
+            .fields
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| selected.contains(i))
+            .map(|(_, (_, out))| out)
+            .collect())
     }
 
-    /// Prompts the field.
-    ///
-    /// It prompts the field until the value provided by the user is correct, then parses it.
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// # Panic
+    /// Prompts the selectable values to the user, and returns the value at the input index,
+    /// or the given fallback value if the input is incorrect.
     ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<T>
+    /// It prompts the selectable values once, and if the user entered a correct index,
+    /// it returns the value mapped to this index, otherwise, it attempts to return the value
+    /// mapped to the default index (see [`Selected::default`]), and if there is no default value,
+    /// or if any error occurred, it returns `fallback`.
+    pub fn select_or<R, W>(self, stream: &mut MenuStream<R, W>, fallback: T) -> T
     where
         R: BufRead,
         W: Write,
-        T: FromStr,
     {
-        self.prompt_with(stream, &self.fmt)
+        self.optional_select(stream)
+            .ok()
+            .flatten()
+            .unwrap_or(fallback)
     }
 
-    /// Prompts the field and returns the input value, or the default value of the type
-    /// if the input is incorrect, using the given format.
-    ///
-    /// It uses the merged version between the format of the written field and the given format.
-    ///
-    /// It prompts the value once, and if the user entered a correct input, it returns the value,
-    /// otherwise, it attempts to return the default value (see [`Written::default_value`]
-    /// or [`Written::default_env`]), and if there is no default value, it returns
-    /// the [default](Default) implementation of `T`.
-    ///
-    /// # Panics
+    /// Prompts the selectable values to the user, and returns the value at the input index,
+    /// or the default index if the input is incorrect.
     ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt_or_default_with<R, W, T>(
-        &self,
-        stream: &mut MenuStream<R, W>,
-        fmt: &Format<'_>,
-    ) -> T
+    /// It prompts the selectable values once, and if the user entered a correct index,
+    /// it returns the value mapped to this index, otherwise, it attempts to return the value
+    /// mapped to the default index (see [`Selected::default`]), and if there is no default value,
+    /// it returns the [default](Default) implementation of `T`.
+    pub fn select_or_default<R, W>(self, stream: &mut MenuStream<R, W>) -> T
     where
         R: BufRead,
         W: Write,
-        T: FromStr + Default,
+        T: Default,
     {
-        self.optional_value_with(stream, fmt)
+        self.optional_select(stream)
             .map(Option::unwrap_or_default)
             .unwrap_or_default()
     }
+}
 
-    /// Prompts the field and returns the input value, or the default value of the type
-    /// if the input is incorrect.
-    ///
-    /// It prompts the value once, and if the user entered a correct input, it returns the value,
-    /// otherwise, it attempts to return the default value (see [`Written::default_value`]
-    /// or [`Written::default_env`]), and if there is no default value, it returns
-    /// the [default](Default) implementation of `T`.
-    ///
-    /// # Panics
-    ///
-    /// If the default value has an incorrect type, this function will panic.
-    pub fn prompt_or_default<R, W, T>(&self, stream: &mut MenuStream<R, W>) -> T
-    where
-        R: BufRead,
-        W: Write,
-        T: FromStr + Default,
-    {
-        self.prompt_or_default_with(stream, &self.fmt)
+impl<T, const N: usize> Display for Selected<'_, T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(render) = self.fmt.custom_render {
+            let labels = self.labels();
+            render(
+                &FieldParts {
+                    prefix: self.fmt.prefix,
+                    msg: &self.fmt.decorated(&self.msg),
+                    chip: self.fmt.chip,
+                    default: self.default_pos().map(|pos| labels[pos]),
+                    example: None,
+                    optional: f.alternate() && self.default.is_none(),
+                    labels: &labels,
+                },
+                f,
+            )?;
+            return match self.fmt.line_brk {
+                true => f.write_str(self.fmt.line_ending.as_str()),
+                false => Ok(()),
+            };
+        }
+
+        write!(f, "{}{}", self.fmt.prefix, self.fmt.decorated(&self.msg))?;
+        if f.alternate() && self.default.is_none() && self.fmt.mark_optional
+            || self.default.is_some() && !self.fmt.mark_default
+        {
+            f.write_str(" (optional)")?;
+        }
+        f.write_str(self.fmt.line_ending.as_str())?;
+
+        let count = self.visible.len();
+        let keys = match self.mnemonics {
+            Some(true) => self.mnemonic_keys(),
+            _ => Vec::new(),
+        };
+        for (i, field) in (1..=count).zip(self.visible.iter().copied()) {
+            for (_, header) in self.groups.iter().filter(|(at, _)| *at == field) {
+                write!(f, "{}{}", header, self.fmt.line_ending)?;
+            }
+            if self.fmt.show_index {
+                write!(
+                    f,
+                    "{}{}{}{}",
+                    self.fmt.left_sur,
+                    padded_index(&self.fmt, i, count),
+                    self.fmt.right_sur,
+                    self.fmt.chip,
+                )?;
+            }
+            let label = self.fields[field].0;
+            match keys.get(i - 1) {
+                Some(Some(_)) => {
+                    let start = label.len() - label.trim_start().len();
+                    let end = start + label[start..].chars().next().map_or(0, char::len_utf8);
+                    write!(
+                        f,
+                        "{}\x1b[4m{}\x1b[0m{}",
+                        &label[..start],
+                        &label[start..end],
+                        &label[end..]
+                    )?;
+                }
+                _ => f.write_str(label)?,
+            }
+            match self.default {
+                Some(x) if x == field && self.fmt.mark_default => f.write_str(" (default)")?,
+                _ => (),
+            }
+            f.write_str(self.fmt.line_ending.as_str())?;
+        }
+
+        Ok(())
     }
 }
 
-/// Used to define a selectable type.
+/// The selectable value chosen by the user, with its label.
 ///
-/// It provides the fields, corresponding to a message and the return value.
-/// It is used by the [`Selected`] struct with its `From<&str>` implementation.
+/// It is returned by the [`Selected::select_choice`] method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Choice<'a, T> {
+    /// The label of the selectable value.
+    pub label: &'a str,
+    /// The index of the selectable value, starting from 0, in the original order.
+    pub index: usize,
+    /// The detail of the selectable value, if the details are provided
+    /// (see [`Selected::details`]).
+    pub detail: Option<&'a str>,
+    /// The selectable value.
+    pub value: T,
+}
+
+/// A field of a form run by a [container](crate::menu::Values::form).
 ///
-/// The `N` const generic parameter represents the amount of available selectable values.
+/// The fields of a form are answered in order, and the user can go back to the previous
+/// field to answer it again (see [`Values::back_key`](crate::menu::Values::back_key)).
 ///
 /// # Example
 ///
+/// ```no_run
+/// # use ezmenulib::prelude::*;
+/// # fn main() -> MenuResult {
+/// let answers = Values::default().form(&[
+///     FormField::Written(&Written::from("Name")),
+///     FormField::Selected(&Selected::new("License", [("MIT", 0), ("GPL", 1)])),
+/// ])?;
+/// # Ok(()) }
 /// ```
-/// # use ezmenulib::field::Selectable;
-/// enum Type {
-///     MIT,
-///     GPL,
-///     BSD,
-/// }
-///
-/// impl Selectable<3> for Type {
-///     fn values() -> [(&'static str, Self); 3] {
-///         [
-///             ("MIT", Self::MIT),
-///             ("GPL", Self::GPL),
-///             ("BSD", Self::BSD),
-///         ]
-///     }
-/// }
-/// ```
-pub trait Selectable<const N: usize>: Sized {
-    /// Provides the fields, corresponding to a message and the return value.
-    fn values() -> [(&'static str, Self); N];
+pub enum FormField<'a, R = In, W = Out> {
+    /// A written field, answered with the input of the user.
+    Written(&'a Written<'a>),
+    /// A selectable field, answered with the label of the selected value.
+    Selected(&'a dyn FormSelect<R, W>),
+}
 
-    /// Provides the default fields, by its index, if it is available.
-    fn default() -> Option<usize> {
-        None
+impl<'a, R, W> FormField<'a, R, W> {
+    /// Returns the message of the field.
+    pub fn msg(&self) -> &str {
+        match *self {
+            Self::Written(written) => written.msg(),
+            Self::Selected(sel) => sel.msg(),
+        }
+    }
+
+    /// Checks that the field is consistent, without prompting it.
+    ///
+    /// The default value of a written field must be a correct answer, for instance
+    /// a boolean for a [boolean](Written::boolean) field (see [`Written::validate`]).
+    /// A selectable field is checked by [`FormSelect::validate`].
+    pub fn validate(&self) -> MenuResult {
+        match *self {
+            Self::Written(written) if written.boolean => written.validate::<bool>(),
+            Self::Written(written) => written.validate::<String>(),
+            Self::Selected(sel) => sel.validate(),
+        }
     }
 }
 
-/// Defines the behavior for a selected value provided by the user.
-///
-/// Like the [written](Written) values, it contains its own [format](Format),
-/// and it can be inherited, saving the custom format specifications.
+impl<R, W> fmt::Debug for FormField<'_, R, W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("FormField::")?;
+        match self {
+            Self::Written(written) => f.debug_tuple("Written").field(written).finish(),
+            Self::Selected(sel) => f.debug_tuple("Selected").field(&sel.msg()).finish(),
+        }
+    }
+}
+
+/// Defines a selectable field of a [form](crate::menu::Values::form), whatever the type
+/// of its values.
 ///
-/// It displays the message with the available fields to select, with the
-/// default field marked as "(default)" if it is provided (see [`Selected::default`] function).
-/// You have to provide a mutable reference to a [`MenuStream`] to retrieve the selected value.
+/// It is implemented by the [selectable](Selected) fields whose values can be cloned,
+/// because the user can go back to the field to select a value again.
+pub trait FormSelect<R = In, W = Out> {
+    /// Returns the message of the field.
+    fn msg(&self) -> &str;
+
+    /// Checks that the selectable values are consistent, without prompting the field
+    /// (see [`Selected::validate`]).
+    fn validate(&self) -> MenuResult;
+
+    /// Returns `true` if the field has a default value.
+    fn has_default(&self) -> bool;
+
+    /// Prompts the field with its format merged with the given one, and returns the label
+    /// of the selected value, or `None` if the user entered the back key.
+    ///
+    /// The value labeled `default`, if any, is used as the default value of the field.
+    fn select_label(
+        &self,
+        stream: &mut MenuStream<'_, R, W>,
+        fmt: &Format<'_>,
+        default: Option<&str>,
+        back_key: &str,
+    ) -> MenuResult<Option<String>>;
+}
+
+impl<R, W, T, const N: usize> FormSelect<R, W> for Selected<'_, T, N>
+where
+    R: BufRead,
+    W: Write,
+    T: Clone,
+{
+    fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    fn validate(&self) -> MenuResult {
+        Selected::validate(self)
+    }
+
+    fn has_default(&self) -> bool {
+        Selected::has_default(self)
+    }
+
+    fn select_label(
+        &self,
+        stream: &mut MenuStream<'_, R, W>,
+        fmt: &Format<'_>,
+        default: Option<&str>,
+        back_key: &str,
+    ) -> MenuResult<Option<String>> {
+        let mut sel = self.clone();
+        sel.fmt = sel.fmt.merged(fmt);
+        if let Some(i) = default.and_then(|d| self.fields.iter().position(|(l, _)| *l == d)) {
+            sel.default = Some(i);
+        }
+        let i = sel.select_index_or_back(stream, Some(back_key))?;
+        Ok(i.map(|i| self.fields[i].0.to_owned()))
+    }
+}
+
+/// Defines the behavior for a value selected by the user among a range of values.
 ///
-/// You can use beside it the [`Selectable`] trait to list the available values to select.
+/// Unlike the [selected](Selected) values, the user does not enter the index of the value,
+/// but directly the value, which must be contained in the range.
+/// It is useful to pick a number from a range, such as a month or an hour, without
+/// listing all the available values.
 ///
-/// The `N` const generic parameter represents the amount of available selectable values.
+/// It displays the message with the bounds of the range, and the default value
+/// if it is provided (see [`SelectedRange::default`] function).
 ///
 /// # Example
 ///
-/// For a make-license CLI program for example, you can use it like below:
-///
 /// ```no_run
 /// use ezmenulib::prelude::*;
 ///
-/// enum Type {
-///     MIT,
-///     GPL,
-///     BSD,
-/// }
-///
-/// impl Selectable<3> for Type {
-///     fn values() -> [(&'static str, Self); 3] {
-///         use Type::*;
-///         [
-///             ("MIT", MIT),
-///             ("GPL", GPL),
-///             ("BSD", BSD),
-///         ]
-///     }
-/// }
-///
-/// let s: Type = Selected::from("Select the license type")
+/// let month: u8 = SelectedRange::new("Pick a month", 1..=12)
 ///     .select(&mut MenuStream::default())
 ///     .unwrap();
 /// ```
-// Clone is implemented on it because it is moved once the user selected the value.
 #[derive(Debug, Clone)]
-pub struct Selected<'a, T, const N: usize> {
-    /// The format used by the selected field value.
+pub struct SelectedRange<'a, T> {
+    /// The format used by the selected range field.
     pub fmt: Format<'a>,
-    msg: &'a str,
-    fields: [(&'a str, T); N],
-    default: Option<usize>,
+    msg: Cow<'a, str>,
+    range: RangeInclusive<T>,
+    default: Option<T>,
 }
 
-impl<'a, T, const N: usize> From<&'a str> for Selected<'a, T, N>
-where
-    T: Selectable<N>,
-{
-    fn from(msg: &'a str) -> Self {
-        Self::inner_new(msg, T::values(), T::default().map(|i| i + 1))
+impl<'a, T> SelectedRange<'a, T> {
+    /// Returns the message of the field.
+    pub(crate) fn msg(&self) -> &str {
+        &self.msg
     }
-}
 
-impl<'a, T, const N: usize> Selected<'a, T, N> {
-    fn inner_new(msg: &'a str, fields: [(&'a str, T); N], default: Option<usize>) -> Self {
-        check_fields(fields.as_ref());
+    /// Returns `true` if the field has a default value.
+    pub(crate) fn has_default(&self) -> bool {
+        self.default.is_some()
+    }
 
+    /// Returns the selected range field using the given message and range of values.
+    pub fn new<M: Into<Cow<'a, str>>>(msg: M, range: RangeInclusive<T>) -> Self {
         Self {
             fmt: Default::default(),
-            msg,
-            fields,
-            default,
+            msg: msg.into(),
+            range,
+            default: None,
         }
     }
 
-    /// Returns the Selected wrapper using the given message and
-    /// selectable fields.
-    ///
-    /// # Note
-    ///
-    /// If `T` implements `Selectable`, you may use the `From<&str>` implementation
-    /// for `Selected`, to not write again the available selectable fields.
-    ///
-    /// # Panic
-    ///
-    /// If the fields array is empty, this function will panic. Indeed,
-    /// when prompting the index to the user to select with an empty list, it will generate an
-    /// infinite loop.
-    pub fn new(msg: &'a str, fields: [(&'a str, T); N]) -> Self {
-        Self::inner_new(msg, fields, None)
-    }
-
-    /// Gives a custom formatting for the selected value.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use ezmenulib::field::{Selected, Format, Selectable};
-    /// enum Type {
-    ///     MIT,
-    ///     GPL,
-    ///     BSD,
-    /// }
-    ///
-    /// let w = Selected::new("Select the license type", [
-    ///     ("MIT", Type::MIT),
-    ///     ("GPL", Type::GPL),
-    ///     ("BSD", Type::BSD),
-    /// ])
-    /// .format(Format::prefix("==> "));
-    /// ```
+    /// Gives a custom formatting for the selected range field.
     pub fn format(mut self, fmt: Format<'a>) -> Self {
         self.fmt = fmt;
-        // Saves the default suffix if asked to break the line,
-        // because it would be ugly to have for instance ": " as suffix.
-        // This is useful if the format is inherited (see [`Values::selected`] function).
-        if !self.fmt.line_brk {
-            self.fmt.suffix = DEFAULT_FMT.suffix;
-        }
         self
     }
 
-    /// Defines the default value among the the selectable values, by its index.
+    /// Defines the default value, selected if the user enters an incorrect value.
     ///
     /// # Note
     ///
-    /// If the index is out of bounds, it will not panic at runtime. Therefore,
-    /// if the user enters an incorrect index, it will not use the default index.
-    pub fn default(mut self, default: usize) -> Self {
-        self.default = Some(default + 1);
+    /// The default value is not required to be contained in the range.
+    pub fn default(mut self, default: T) -> Self {
+        self.default = Some(default);
         self
     }
+}
 
-    /// Prompts the selectable fields once.
-    ///
-    /// In fact, it only displays the suffix, and gets the user input, then returns
-    /// the correct index wrapped in a `Query`.
+impl<T> SelectedRange<'_, T>
+where
+    T: FromStr + PartialOrd + Clone,
+{
+    /// Prompts the suffix once, and returns the value if it is contained in the range,
+    /// otherwise the default value if it is provided.
     fn prompt_once<R: BufRead, W: Write>(
         &self,
         stream: &mut MenuStream<R, W>,
-    ) -> MenuResult<Option<usize>> {
-        select(stream, self.fmt.suffix, N).map(|o| o.or(self.default))
-    }
-
-    /// Prompts the selectable fields and returns the value at the input index,
-    /// or `None` if the index is incorrect.
-    ///
-    /// It prompts the selectable fields once, and if the user entered a correct index,
-    /// it returns `Some(value)` where `value` corresponds to the value mapped to this index,
-    /// otherwise, it attempts to return the value mapped by the default index
-    /// (see `Selected::default`), and if there is no default index, it returns `None`.
-    ///
-    /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]).
-    pub fn optional_select<R, W>(self, stream: &mut MenuStream<R, W>) -> MenuResult<Option<T>>
-    where
-        R: BufRead,
-        W: Write,
-    {
-        // Uses the alternate form of selection field display
-        // to display the "(optional)" string slice message.
-        show(&format!("{:#}", self), stream)?;
-
-        Ok(self.prompt_once(stream)?.map(|i| {
-            // SAFETY: the `Selected::prompt_once` guarantees that the index is in bounds.
-            unsafe { self.take(i) }
-        }))
-    }
+    ) -> MenuResult<Option<T>> {
+        let s = match prompt(&self.fmt, stream) {
+            Err(MenuError::Eof) if self.default.is_some() => String::new(),
+            res => res?,
+        };
 
-    /// Gives the value stored at index `i`, consuming `self`.
-    ///
-    /// The index must be in bounds, or this will cause an undefined behavior.
-    ///
-    /// # Safety
-    ///
-    /// The `i` index must be in bounds, meaning `i < N`.
-    /// Otherwise, this function results in an undefined behavior.
-    unsafe fn take(self, i: usize) -> T {
-        self.fields.into_iter().nth(i).unwrap_unchecked().1
+        Ok(s.parse()
+            .ok()
+            .filter(|v| self.range.contains(v))
+            .or_else(|| self.default.clone()))
     }
 
-    /// Prompts the selectable values to the user.
+    /// Prompts the field to the user, and returns the value they entered.
     ///
-    /// It prompts the fields once and the suffix until the index provided, then returns the selected value.
+    /// It prompts the suffix until the user enters a value contained in the range,
+    /// or the default value is used (see [`SelectedRange::default`]).
     /// The output is wrapped in a [`MenuResult`] to prevent from any error (see [`MenuError`]);
-    ///
-    /// This function consumes `self` because it returns the ownership of a contained value
-    /// (`T`) defined earlier in the [`Selected::new`] function.
-    pub fn select<R, W>(self, stream: &mut MenuStream<R, W>) -> MenuResult<T>
+    pub fn select<R, W>(&self, stream: &mut MenuStream<R, W>) -> MenuResult<T>
     where
         R: BufRead,
         W: Write,
+        T: Display,
     {
-        show(&self, stream)?;
+        if !self.fmt.quiet {
+            write!(stream, "{}", self)?;
+        }
         loop {
             match self.prompt_once(stream)? {
-                // SAFETY: the `Selected::prompt_once` guarantees that the index is in bounds.
-                Some(out) => return Ok(unsafe { self.take(out) }),
-                None => continue,
+                Some(out) => return Ok(out),
+                None => show_invalid(&self.fmt, stream)?,
             }
         }
     }
-
-    /// Prompts the selectable values to the user, and returns the value at the input index,
-    /// or the default index if the input is incorrect.
-    ///
-    /// It prompts the selectable values once, and if the user entered a correct index,
-    /// it returns the value mapped to this index, otherwise, it attempts to return the value
-    /// mapped to the default index (see [`Selected::default`]), and if there is no default value,
-    /// it returns the [default](Default) implementation of `T`.
-    pub fn select_or_default<R, W>(self, stream: &mut MenuStream<R, W>) -> T
-    where
-        R: BufRead,
-        W: Write,
-        T: Default,
-    {
-        self.optional_select(stream)
-            .map(Option::unwrap_or_default)
-            .unwrap_or_default()
-    }
 }
 
-impl<T, const N: usize> Display for Selected<'_, T, N> {
+impl<T: Display> Display for SelectedRange<'_, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", self.fmt.prefix, self.msg)?;
-        if f.alternate() && self.default.is_none()
-            || self.default.is_some() && !self.fmt.show_default
-        {
-            f.write_str(" (optional)")?;
-        }
-        f.write_str("\n")?;
-
-        for (i, (msg, _)) in (1..=N).zip(self.fields.iter()) {
-            write!(
-                f,
-                "{}{i}{}{}{msg}",
-                self.fmt.left_sur, self.fmt.right_sur, self.fmt.chip
-            )?;
-            match self.default {
-                Some(x) if x == i && self.fmt.show_default => f.write_str(" (default)")?,
-                _ => (),
-            }
-            f.write_str("\n")?;
+        write!(
+            f,
+            "{}{} ({}–{}",
+            self.fmt.prefix,
+            self.fmt.decorated(&self.msg),
+            self.range.start(),
+            self.range.end()
+        )?;
+        match self.default {
+            Some(ref d) if self.fmt.show_default => write!(f, ", default: {}", d)?,
+            _ => (),
         }
-
-        Ok(())
+        write!(f, "){}", self.fmt.line_ending)
     }
 }
 
@@ -951,6 +3863,39 @@ pub type Field<'a, R = In, W = Out> = (&'a str, Kind<'a, R, W>);
 ///
 /// It simply corresponds to a slice of fields.
 /// It is used for more convenience in the library.
+///
+/// # Actions from an enum
+///
+/// If the actions of a menu are listed by an enum, the [`Selectable`] trait may be used
+/// instead of writing the fields, by selecting the action then matching it in a loop:
+///
+/// ```no_run
+/// use ezmenulib::prelude::*;
+///
+/// #[derive(Clone, Copy)]
+/// enum Action {
+///     Add,
+///     Remove,
+///     Quit,
+/// }
+///
+/// impl Selectable<3> for Action {
+///     fn values() -> [(&'static str, Self); 3] {
+///         [("Add", Self::Add), ("Remove", Self::Remove), ("Quit", Self::Quit)]
+///     }
+/// }
+///
+/// # fn main() -> MenuResult {
+/// let mut stream = MenuStream::default();
+/// loop {
+///     match Selected::<Action, 3>::from("Action").select(&mut stream)? {
+///         Action::Add => { /* ... */ }
+///         Action::Remove => { /* ... */ }
+///         Action::Quit => break,
+///     }
+/// }
+/// # Ok(()) }
+/// ```
 pub type Fields<'a, R = In, W = Out> = &'a [Field<'a, R, W>];
 
 /// Corresponds to the function mapped to a field.
@@ -976,6 +3921,27 @@ pub enum Kind<'a, R = In, W = Out> {
     Quit,
 }
 
+impl<'a, R, W> Kind<'a, R, W> {
+    /// Returns the fields of the sub-menu if the kind is a [parent](Kind::Parent) field,
+    /// otherwise `None`.
+    ///
+    /// It is useful to walk through the fields of a menu without running it,
+    /// for instance to render it with another interface.
+    pub fn children(&self) -> Option<Fields<'a, R, W>> {
+        match self {
+            Self::Parent(fields) => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the kind is a [mapped](Kind::Map) field.
+    ///
+    /// The mapped function itself cannot be inspected.
+    pub fn is_map(&self) -> bool {
+        matches!(self, Self::Map(_))
+    }
+}
+
 impl<'a, R, W> fmt::Debug for Kind<'a, R, W> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.write_str("Field::")?;
@@ -6,3 +6,64 @@ fn fmt_merge() {
     let new = fmt.merged(&Format::suffix("> "));
     assert_eq!(new.suffix, "--> ");
 }
+
+#[test]
+fn fmt_setters() {
+    let fmt = Format::prefix("==> ").with_chip(" = ").with_line_brk(false);
+    assert_eq!(fmt.prefix, "==> ");
+    assert_eq!(fmt.chip, " = ");
+    assert!(!fmt.line_brk);
+    assert_eq!(fmt.suffix, Format::default().suffix);
+}
+
+#[test]
+fn validate() {
+    use crate::field::{Selected, Written};
+
+    assert!(Written::from("age").validate::<u8>().is_ok());
+    assert!(Written::from("age")
+        .default_value("18")
+        .validate::<u8>()
+        .is_ok());
+    assert!(Written::from("age")
+        .default_value("-1")
+        .validate::<u8>()
+        .is_err());
+    assert!(Written::from("age").range(1..=120).validate::<u8>().is_ok());
+    assert!(Written::from("ratio")
+        .range(0.5..=1.5)
+        .validate::<u8>()
+        .is_err());
+
+    assert!(Selected::new("amount", [("one", 1), ("two", 2)])
+        .validate()
+        .is_ok());
+    assert!(Selected::new("amount", [("one", 1), ("two", 2)])
+        .default(2)
+        .validate()
+        .is_err());
+    assert!(Selected::new("amount", [("one", 1), ("One", 2)])
+        .validate()
+        .is_err());
+    assert!(matches!(
+        Selected::<u8, 0>::new("amount", []).validate(),
+        Err(crate::MenuError::EmptyMenu)
+    ));
+    assert!(matches!(
+        Selected::new("amount", [("one", 1), ("two", 2)])
+            .filter(|n| *n > 2)
+            .validate(),
+        Err(crate::MenuError::EmptyMenu)
+    ));
+}
+
+#[test]
+fn normalization() {
+    use crate::field::Normalization;
+
+    let s = "  Hello   World ";
+    assert_eq!(Normalization::Lower.apply(s), "hello   world");
+    assert_eq!(Normalization::Upper.apply(s), "HELLO   WORLD");
+    assert_eq!(Normalization::TrimOnly.apply(s), "Hello   World");
+    assert_eq!(Normalization::CollapseWhitespace.apply(s), "Hello World");
+}
@@ -24,6 +24,9 @@
 )]
 #![cfg_attr(nightly, feature(doc_cfg))]
 
+#[cfg(test)]
+mod tests;
+
 #[cfg(all(feature = "tui", any(feature = "crossterm", feature = "termion")))]
 #[cfg_attr(nightly, doc(cfg(feature = "tui")))]
 pub mod tui;
@@ -32,6 +35,8 @@ pub mod customs;
 pub mod field;
 pub mod menu;
 
+#[cfg(feature = "serde")]
+mod de;
 mod utils;
 
 /// Module used to import common structs, to build menus with their fields.
@@ -43,7 +48,7 @@ pub mod prelude {
     pub use crate::MenuResult;
 }
 
-use crate::field::Format;
+use crate::field::{Format, LineEnding, MatchMode};
 use std::env::VarError;
 use std::error::Error;
 use std::fmt::{self, Debug, Display, Formatter};
@@ -53,19 +58,52 @@ pub(crate) const DEFAULT_FMT: Format<'static> = Format {
     prefix: "--> ",
     chip: " - ",
     show_default: true,
+    mark_default: true,
+    mark_optional: true,
     suffix: ">> ",
     line_brk: true,
     left_sur: "[",
     right_sur: "]",
+    match_mode: MatchMode::IndexFirst,
+    invalid_msg: "",
+    echo_newline: false,
+    custom_render: None,
+    quiet: false,
+    index_pad: None,
+    show_index: true,
+    line_ending: LineEnding::Lf,
+    show_range: false,
+    index_start: 1,
+    decorate: None,
+    echo_msg: "Selected: ",
 };
 
 /// The error type used by the menu builder.
+///
+/// The enum is non-exhaustive, because new variants may be added for new failure modes.
+/// The errors of the mapped functions of a menu can be converted to this type,
+/// with the `From` implementations, or the [`MenuError::other`] constructor.
 #[non_exhaustive]
 pub enum MenuError {
     /// An IO error, when flushing, reading or writing values.
     IOError(io::Error),
     /// A parsing error for a value.
     Input,
+    /// The input stream reached its end while a value was expected.
+    Eof,
+    /// The user did not provide any input before the timeout of the menu session.
+    Timeout,
+    /// The user did not select any value after the maximum amount of attempts.
+    NoSelection,
+    /// The menu or the selectable field does not contain any value to select,
+    /// for instance because all of them have been filtered out.
+    EmptyMenu,
+    /// The user interrupted a form before answering all its fields, because the input stream
+    /// reached its end (see [`Values::form`](crate::menu::Values::form)).
+    ///
+    /// It contains the answers given so far, as the message of each answered field
+    /// with its recorded answer, masked for a secret field, so the form can be resumed later.
+    Interrupted(Vec<(String, String)>),
     /// An environment variable error.
     EnvVar(String, VarError),
     /// An error occurred when formatting a field.
@@ -82,6 +120,32 @@ impl PartialEq for MenuError {
     }
 }
 
+impl MenuError {
+    /// Returns a custom error wrapping the given value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ezmenulib::MenuError;
+    ///
+    /// #[derive(Debug)]
+    /// struct NotFound(u32);
+    ///
+    /// let err = MenuError::other(NotFound(42));
+    /// assert_eq!(err.to_string(), "NotFound(42)");
+    /// ```
+    #[inline]
+    pub fn other<D: Debug + 'static>(d: D) -> Self {
+        Self::Other(Box::new(d))
+    }
+
+    /// Returns an environment variable error, for the given variable name.
+    #[inline]
+    pub fn env_var<S: Into<String>>(var: S, e: VarError) -> Self {
+        Self::EnvVar(var.into(), e)
+    }
+}
+
 impl Error for MenuError {}
 
 impl Debug for MenuError {
@@ -97,6 +161,16 @@ impl Display for MenuError {
             match self {
                 Self::IOError(e) => format!("IO error: {}", e),
                 Self::Input => "an incorrect input has been provided".to_owned(),
+                Self::Eof => "the input stream reached its end".to_owned(),
+                Self::Timeout => "the menu session timed out".to_owned(),
+                Self::NoSelection => {
+                    "no value has been selected after the maximum amount of attempts".to_owned()
+                }
+                Self::EmptyMenu => "there is no value to select".to_owned(),
+                Self::Interrupted(answers) => format!(
+                    "the form has been interrupted after {} answer(s)",
+                    answers.len()
+                ),
                 Self::EnvVar(v, e) => format!(
                     "attempted to get a default value from the environment variable `{}`: {}",
                     v, e
@@ -136,5 +210,32 @@ impl From<fmt::Error> for MenuError {
     }
 }
 
+impl From<Box<dyn Error>> for MenuError {
+    #[inline]
+    fn from(e: Box<dyn Error>) -> Self {
+        Self::Other(Box::new(e))
+    }
+}
+
+/// Implements the conversion from the given parsing error types,
+/// which correspond to an incorrect input.
+macro_rules! impl_from_parse {
+    ($($t:ty),*) => {$(
+        impl From<$t> for MenuError {
+            #[inline]
+            fn from(_: $t) -> Self {
+                Self::Input
+            }
+        }
+    )*}
+}
+
+impl_from_parse!(
+    std::num::ParseIntError,
+    std::num::ParseFloatError,
+    std::str::ParseBoolError,
+    std::char::ParseCharError
+);
+
 /// The main result type used in the EZMenu library.
 pub type MenuResult<T = ()> = Result<T, MenuError>;
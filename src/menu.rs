@@ -5,14 +5,25 @@ mod tests;
 
 mod stream;
 
-pub use crate::menu::stream::{MenuStream, Mutable};
+#[cfg(feature = "serde")]
+#[cfg_attr(nightly, doc(cfg(feature = "serde")))]
+pub use crate::de::from_map;
+#[cfg(feature = "json")]
+#[cfg_attr(nightly, doc(cfg(feature = "json")))]
+pub use crate::de::JsonType;
+pub use crate::menu::stream::{assert_prompts, MenuStream, Mutable, RingWriter, StripAnsi};
 use crate::prelude::*;
-use crate::utils::{check_fields, select, Depth};
+pub use crate::utils::Spinner;
+use crate::utils::{
+    check_fields, padded_index, prompt, select, show_invalid, terminal_height, Depth,
+};
 
+use std::borrow::Cow;
 use std::fmt::{self, Display, Formatter};
-use std::io::{BufRead, BufReader, Stdin, Stdout, Write};
+use std::io::{BufRead, BufReader, Cursor, Stdin, Stdout, Write};
 use std::ops::{Deref, DerefMut};
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 /// The default input stream used by a menu, using the standard input stream.
 pub type In = BufReader<Stdin>;
@@ -20,6 +31,10 @@ pub type In = BufReader<Stdin>;
 /// The default output stream used by a menu, using the standard output stream.
 pub type Out = Stdout;
 
+/// The function called by a menu when the user input does not match any field,
+/// with the input and the stream (see [`RawMenu::on_invalid`]).
+pub type InvalidHook<R = In, W = Out> = fn(&str, &mut MenuStream<R, W>) -> MenuResult;
+
 /// Used to retrieve the object from a container.
 ///
 /// The object may be either owned or mutably borrowed.
@@ -40,6 +55,34 @@ pub trait UsesMutable<T> {
     fn get_mut_object(&mut self) -> &mut T;
 }
 
+/// Used to prompt a field with a [`Values`] container, answering it with a string.
+///
+/// Unlike the methods of the container, its output type does not depend on the field,
+/// so it is object-safe: fields of different types can be stored in the same collection,
+/// as `Box<dyn Promptable<R, W>>`, to build a form at runtime (see [`Values::run_all`]).
+///
+/// It is implemented by the [written](Written) fields, answered with the input of the user,
+/// and by the [selectable](Selected) fields, answered with the label of the selected value.
+pub trait Promptable<R = In, W = Out> {
+    /// Prompts the field with the given container, consuming the field,
+    /// and returns the answer of the user.
+    ///
+    /// The answer is recorded by the container, like with its other methods.
+    fn prompt_in(self: Box<Self>, values: &mut Values<'_, R, W>) -> MenuResult<String>;
+}
+
+impl<R: BufRead, W: Write> Promptable<R, W> for Written<'_> {
+    fn prompt_in(self: Box<Self>, values: &mut Values<'_, R, W>) -> MenuResult<String> {
+        values.written(&self)
+    }
+}
+
+impl<R: BufRead, W: Write, T, const N: usize> Promptable<R, W> for Selected<'_, T, N> {
+    fn prompt_in(self: Box<Self>, values: &mut Values<'_, R, W>) -> MenuResult<String> {
+        values.selected_labeled(*self).map(|(label, _)| label)
+    }
+}
+
 /// Used to instantiate a container from a mutable object,
 /// and the rest of the required arguments.
 ///
@@ -120,11 +163,34 @@ pub trait FromMutable<'a, S: 'a, Arg>: Sized {
 /// // We can also give the ownership:
 /// // let mut menu = Values::from(my_stream);
 /// ```
+///
+/// ## Returning the container
+///
+/// A container using a borrowed stream cannot leave the scope of the borrow, for instance
+/// to be returned from a function, because the stream is not cloneable, and the format
+/// shares the lifetime of the borrow. In this case, give the ownership of the stream
+/// to the container instead, then retrieve it with the [`Values::take_object`] method
+/// once you are done. You can check it with the [`Values::owns_stream`] method:
+/// ```no_run
+/// # use ezmenulib::prelude::*;
+/// fn form() -> Values<'static> {
+///     Values::from(MenuStream::default()).format(Format::prefix("==> "))
+/// }
+///
+/// let menu = form();
+/// if menu.owns_stream() {
+///     let stream = menu.take_object();
+/// }
+/// ```
 #[derive(Debug)]
 pub struct Values<'a, R = In, W = Out> {
     /// The global format of the container.
     pub fmt: Format<'a>,
     stream: Mutable<'a, MenuStream<'a, R, W>>,
+    records: Option<Vec<(String, String)>>,
+    defaults: Vec<String>,
+    banner: Option<&'a str>,
+    back_key: &'a str,
 }
 
 /// Returns the default container, which corresponds to the
@@ -139,6 +205,10 @@ impl Default for Values<'_> {
         Self {
             fmt: Format::default(),
             stream: Mutable::default(),
+            records: None,
+            defaults: Vec::new(),
+            banner: None,
+            back_key: ":prev",
         }
     }
 }
@@ -170,7 +240,14 @@ impl<'a> From<Format<'a>> for Values<'a> {
 
 impl<'a, R, W> FromMutable<'a, MenuStream<'a, R, W>, Format<'a>> for Values<'a, R, W> {
     fn new(stream: Mutable<'a, MenuStream<'a, R, W>>, fmt: Format<'a>) -> Self {
-        Self { fmt, stream }
+        Self {
+            fmt,
+            stream,
+            records: None,
+            defaults: Vec::new(),
+            banner: None,
+            back_key: ":prev",
+        }
     }
 }
 
@@ -184,6 +261,195 @@ impl<'a, R, W> Values<'a, R, W> {
         self.fmt = fmt;
         self
     }
+
+    /// Defines if the container records the answers of the user (`false` by default).
+    ///
+    /// Each answer is recorded as the message of the field with the displayed value:
+    /// the label for the [selected](Selected) values, the value for the
+    /// [selected range](SelectedRange) values, and the input for the [written](Written) values.
+    /// The optional selected values, or the ones with a fallback, are not recorded.
+    ///
+    /// The answers can be reviewed by the user with the [`Values::confirm_summary`] method.
+    pub fn record(mut self, record: bool) -> Self {
+        self.records = record.then(Vec::new);
+        self
+    }
+
+    /// Returns the format resolved from the given format of a field and the global format
+    /// of the container, which is the format used when prompting the field.
+    ///
+    /// See [`Format::merged`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let menu = Values::default().format(Format::prefix("==> "));
+    /// let name = Written::from("Name").format(Format::suffix("> "));
+    ///
+    /// let fmt = menu.resolved_format(&name.fmt);
+    /// assert_eq!(fmt.prefix, "==> ");
+    /// assert_eq!(fmt.suffix, "> ");
+    /// ```
+    pub fn resolved_format<'f>(&self, fmt: &Format<'f>) -> Format<'f>
+    where
+        'a: 'f,
+    {
+        fmt.merged(&self.fmt)
+    }
+
+    /// Defines the banner of the form, displayed once before the first field
+    /// the container retrieves a value from.
+    ///
+    /// It is useful to introduce the form, for instance with the name of the program.
+    /// Unlike the title of a [`RawMenu`], it is not displayed again afterwards.
+    /// It is not displayed if the global format is quiet (see [`Format::quiet`]).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let mut form = Values::default().banner("== Account creation ==");
+    /// let name: String = form.written(&Written::from("Name"))?;
+    /// let age: u8 = form.written(&Written::from("Age"))?;
+    /// # Ok(()) }
+    /// ```
+    pub fn banner(mut self, banner: &'a str) -> Self {
+        self.banner = Some(banner);
+        self
+    }
+
+    /// Defines the key entered by the user to go back to the previous field of a form
+    /// (`":prev"` by default).
+    ///
+    /// See [`Values::form`] for more information.
+    pub fn back_key(mut self, key: &'a str) -> Self {
+        self.back_key = key;
+        self
+    }
+
+    /// Returns `true` if the container owns its stream, meaning the stream can be retrieved
+    /// with the [`Values::take_object`] method.
+    pub fn owns_stream(&self) -> bool {
+        self.stream.is_owned()
+    }
+
+    /// Returns the answers recorded by the container (see [`Values::record`]),
+    /// as the message of each field with the displayed value.
+    pub fn records(&self) -> &[(String, String)] {
+        self.records.as_deref().unwrap_or_default()
+    }
+
+    /// Deserializes the answers recorded by the container (see [`Values::record`])
+    /// into the output type, using the message of each field as the name of the
+    /// corresponding struct field.
+    ///
+    /// It bridges the forms built at runtime with the typed configurations.
+    /// See [`from_map`] for more information about how the answers are deserialized.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ezmenulib::prelude::*;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Config {
+    ///     name: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// # fn main() -> MenuResult {
+    /// let mut form = Values::default().record(true);
+    /// let _: String = form.written(&Written::from("name"))?;
+    /// let _: u16 = form.written(&Written::from("port"))?;
+    /// let config: Config = form.into_struct()?;
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "serde")]
+    #[cfg_attr(nightly, doc(cfg(feature = "serde")))]
+    pub fn into_struct<T: serde::de::DeserializeOwned>(&self) -> MenuResult<T> {
+        from_map(self.records().iter().map(|(msg, value)| (msg, value)))
+    }
+
+    /// Returns the messages of the fields that resolved to their default value,
+    /// because the user did not enter any input.
+    ///
+    /// It is useful to warn the user about the values they did not configure, for instance
+    /// with `"3 settings are still at their defaults"`. Unlike [`Values::records`],
+    /// the fields are always tracked. The optional selected values, or the ones
+    /// with a fallback, are not tracked.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let mut menu = Values::default();
+    /// let port: u16 = menu.written(&Written::from("Port").default_value("8080"))?;
+    /// let host: String = menu.written(&Written::from("Host").default_value("localhost"))?;
+    ///
+    /// if !menu.defaults().is_empty() {
+    ///     println!("{} settings are still at their defaults", menu.defaults().len());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn defaults(&self) -> &[String] {
+        &self.defaults
+    }
+
+    /// Tracks the field with the given message if it resolved to its default value
+    /// (see [`Values::defaults`]).
+    fn track_default(&mut self, msg: &str, used_default: bool) {
+        if used_default {
+            self.defaults.push(msg.to_owned());
+        }
+    }
+
+    /// Tracks the selectable field with the given message, from the last input of the user.
+    ///
+    /// The field resolved to its default value if it has any, and if the user entered
+    /// an empty input, or did not enter any input.
+    fn track_selected(&mut self, msg: &str, has_default: bool) {
+        let input = self.stream.take_last_input();
+        self.track_default(msg, has_default && input.unwrap_or_default().is_empty());
+    }
+
+    /// Shows the banner of the form if it has not been displayed yet (see [`Values::banner`]).
+    fn show_banner(&mut self) -> MenuResult
+    where
+        W: Write,
+    {
+        match self.banner.take() {
+            Some(banner) if !self.fmt.quiet => Ok(write!(
+                self.stream.deref_mut(),
+                "{}{}",
+                banner,
+                self.fmt.line_ending
+            )?),
+            _ => Ok(()),
+        }
+    }
+
+    /// Records the given answer, if the container records the answers.
+    fn push_record(&mut self, record: (String, String)) {
+        // The last input is reset, so it is not recorded by the next written field
+        // if it does not read any input.
+        self.stream.take_last_input();
+        if let Some(ref mut records) = self.records {
+            records.push(record);
+        }
+    }
+
+    /// Records the answer of the given written field, from the last input of the user.
+    fn record_written(&mut self, written: &Written<'_>) {
+        let input = self.stream.take_last_input();
+        let used_default = written.used_default(input.as_deref());
+        let record = written.recorded(input);
+        self.track_default(&record.0, used_default);
+        self.push_record(record);
+    }
 }
 
 impl<'a, R, W> UsesMutable<MenuStream<'a, R, W>> for Values<'a, R, W> {
@@ -220,8 +486,24 @@ where
     ///
     /// See [`Selected::select`] function fore more information.
     pub fn selected<T, const N: usize>(&mut self, sel: Selected<'_, T, N>) -> MenuResult<T> {
+        self.selected_labeled(sel).map(|(_, out)| out)
+    }
+
+    /// Returns the next value selected by the user, with its label.
+    ///
+    /// See [`Values::selected`] for more information.
+    fn selected_labeled<T, const N: usize>(
+        &mut self,
+        sel: Selected<'_, T, N>,
+    ) -> MenuResult<(String, T)> {
         let fmt = sel.fmt.merged(&self.fmt);
-        sel.format(fmt).select(self.stream.deref_mut())
+        let (msg, has_default) = (sel.msg().to_owned(), sel.has_default());
+        self.show_banner()?;
+        let (label, out) = sel.format(fmt).select_labeled(self.stream.deref_mut())?;
+        self.track_selected(&msg, has_default);
+        let label = label.to_owned();
+        self.push_record((msg, label.clone()));
+        Ok((label, out))
     }
 
     /// Returns the next value selected by the user wrapped as `Some(value)`,
@@ -236,7 +518,10 @@ where
         sel: Selected<'_, T, N>,
     ) -> MenuResult<Option<T>> {
         let fmt = sel.fmt.merged(&self.fmt);
-        sel.format(fmt).optional_select(self.stream.deref_mut())
+        self.show_banner()?;
+        let out = sel.format(fmt).optional_select(self.stream.deref_mut());
+        self.stream.take_last_input();
+        out
     }
 
     /// Returns the next value selected by the user, or the default value of the output type
@@ -250,8 +535,45 @@ where
     where
         T: Default,
     {
-        let fmt = self.fmt.merged(&self.fmt);
-        sel.format(fmt).select_or_default(self.stream.deref_mut())
+        let fmt = sel.fmt.merged(&self.fmt);
+        let _ = self.show_banner();
+        let out = sel.format(fmt).select_or_default(self.stream.deref_mut());
+        self.stream.take_last_input();
+        out
+    }
+
+    /// Returns the next value selected by the user, or the given fallback value
+    /// if any error occurred.
+    ///
+    /// It merges the [format](Format) of the field with the global format of the container.
+    /// The merge saves the custom formatting specification of the selectable fields.
+    ///
+    /// See [`Selected::select_or`] function for more information.
+    pub fn selected_or<T, const N: usize>(&mut self, sel: Selected<'_, T, N>, fallback: T) -> T {
+        let fmt = sel.fmt.merged(&self.fmt);
+        let _ = self.show_banner();
+        let out = sel.format(fmt).select_or(self.stream.deref_mut(), fallback);
+        self.stream.take_last_input();
+        out
+    }
+
+    /// Returns the next value selected by the user among the given range.
+    ///
+    /// It merges the [format](Format) of the field with the global format of the container.
+    /// The merge saves the custom formatting specification of the selected range field.
+    ///
+    /// See [`SelectedRange::select`] function for more information.
+    pub fn selected_range<T>(&mut self, sel: SelectedRange<'_, T>) -> MenuResult<T>
+    where
+        T: FromStr + PartialOrd + Clone + Display,
+    {
+        let fmt = sel.fmt.merged(&self.fmt);
+        let (msg, has_default) = (sel.msg().to_owned(), sel.has_default());
+        self.show_banner()?;
+        let out = sel.format(fmt).select(self.stream.deref_mut())?;
+        self.track_selected(&msg, has_default);
+        self.push_record((msg, out.to_string()));
+        Ok(out)
     }
 
     /// Returns the next value written by the user.
@@ -269,7 +591,336 @@ where
     where
         T: FromStr,
     {
-        written.prompt_with(self.stream.deref_mut(), &self.fmt)
+        self.show_banner()?;
+        let out = written.prompt_with(self.stream.deref_mut(), &self.fmt)?;
+        self.record_written(written);
+        Ok(out)
+    }
+
+    /// Prompts the given written fields, and returns their answers collected into a JSON
+    /// object, each one placed at the path given by its dotted key.
+    ///
+    /// For instance, the `"server.host"` and `"server.port"` keys produce the
+    /// `{"server": {"host": ..., "port": ...}}` object. Each answer is parsed according
+    /// to the [JSON type](JsonType) declared with its field, so the field is prompted again
+    /// if the answer is incorrect.
+    ///
+    /// It returns a [`MenuError::Other`] error if a key goes through the value of another
+    /// key, for instance with the `"server"` and `"server.port"` keys.
+    /// See [`Values::written`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let payload = Values::default().written_json(&[
+    ///     ("server.host", Written::from("Host").default_value("localhost"), JsonType::String),
+    ///     ("server.port", Written::from("Port").default_value("8080"), JsonType::Integer),
+    ///     ("verbose", Written::from("Verbose mode").boolean(), JsonType::Bool),
+    /// ])?;
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "json")]
+    #[cfg_attr(nightly, doc(cfg(feature = "json")))]
+    pub fn written_json(
+        &mut self,
+        fields: &[(&str, Written<'_>, JsonType)],
+    ) -> MenuResult<serde_json::Value> {
+        use serde_json::Value as Json;
+
+        let mut root = serde_json::Map::new();
+        for (key, written, ty) in fields {
+            let value = match ty {
+                JsonType::String => Json::String(self.written(written)?),
+                JsonType::Integer => Json::from(self.written::<i64>(written)?),
+                JsonType::Float => {
+                    Json::from(self.written_until(written, |n: &f64| n.is_finite())?)
+                }
+                JsonType::Bool => Json::Bool(self.written(written)?),
+            };
+            crate::de::insert_dotted(&mut root, key, value)?;
+        }
+        Ok(serde_json::Value::Object(root))
+    }
+
+    /// Runs the given fields as a form, and returns the answers of the user in order.
+    ///
+    /// Unlike the other methods, the container drives the sequence of the fields, so the user
+    /// can go back to the previous field by entering the [back key](Values::back_key)
+    /// at any prompt, and answer it again. The answers of the next fields are kept,
+    /// as the default values of the fields when they are prompted again.
+    ///
+    /// The answer of a written field is its input, and the answer of a selectable field
+    /// is the label of the selected value, so they can be parsed afterward. The answers are
+    /// recorded once the form is done, like with [`Values::written`] and [`Values::selected`]:
+    /// the answers of the secret fields are masked (see [`Written::secret_env`]), and the fields
+    /// that resolved to their default value are tracked (see [`Values::defaults`]).
+    ///
+    /// If the input stream reaches its end before all the fields are answered, for instance
+    /// because the user pressed Ctrl-D, it returns a [`MenuError::Interrupted`] error
+    /// containing the records of the answers given so far, which are recorded as well.
+    ///
+    /// Pressing Ctrl-C still terminates the process, unless the application handles
+    /// the signal itself, so the answers are lost. Only the prompts reading the key events
+    /// in raw mode, with the `crossterm` feature, end the input on Ctrl-C like on Ctrl-D.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let answers = Values::default().form(&[
+    ///     FormField::Written(&Written::from("Name")),
+    ///     FormField::Written(&Written::from("Age")),
+    ///     FormField::Selected(&Selected::new("License", [("MIT", 0), ("GPL", 1)])),
+    /// ])?;
+    /// let age: u8 = answers[1].parse().map_err(|_| MenuError::Input)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn form(&mut self, fields: &[FormField<'_, R, W>]) -> MenuResult<Vec<String>>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        self.show_banner()?;
+        let mut answers: Vec<Option<FormAnswer>> = vec![None; fields.len()];
+        let mut i = 0;
+        while let Some(field) = fields.get(i) {
+            match self.form_answer(field, answers[i].as_ref()) {
+                Ok(Some(answer)) => {
+                    answers[i] = Some(answer);
+                    i += 1;
+                }
+                Ok(None) => i = i.saturating_sub(1),
+                Err(MenuError::Eof) => {
+                    return Err(MenuError::Interrupted(self.record_form(&answers)))
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        self.record_form(&answers);
+        Ok(answers.into_iter().flatten().map(|a| a.answer).collect())
+    }
+
+    /// Checks that the given fields of a form are consistent, without prompting them
+    /// nor reading from the stream.
+    ///
+    /// It returns the error of the first incorrect field, instead of panicking once the form
+    /// is running, for instance because of an incorrect default value or an empty list
+    /// of selectable values (see [`FormField::validate`]). It is useful as a pre-flight check
+    /// of a complex form, at the start of the program.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ezmenulib::prelude::*;
+    /// let fields = [
+    ///     FormField::Written(&Written::from("Overwrite?").boolean().default_value("maybe")),
+    ///     FormField::Selected(&Selected::new("License", [("MIT", 0), ("GPL", 1)])),
+    /// ];
+    /// assert!(Values::default().validate(&fields).is_err());
+    /// ```
+    pub fn validate(&self, fields: &[FormField<'_, R, W>]) -> MenuResult {
+        fields.iter().try_for_each(FormField::validate)
+    }
+
+    /// Prompts the given fields in order, whatever their type, and returns the answers
+    /// of the user in order.
+    ///
+    /// The fields are stored as trait objects (see [`Promptable`]), so the form can be
+    /// assembled at runtime. The answer of a written field is the input of the user,
+    /// and the answer of a selectable field is the label of the selected value.
+    /// The answers are recorded like with the other methods of the container.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let mut fields: Vec<Box<dyn Promptable>> = vec![Box::new(Written::from("Name"))];
+    /// if std::env::args().any(|a| a == "--license") {
+    ///     fields.push(Box::new(Selected::new("License", [("MIT", 0), ("GPL", 1)])));
+    /// }
+    /// let answers = Values::default().run_all(fields)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn run_all<'f>(
+        &mut self,
+        fields: Vec<Box<dyn Promptable<R, W> + 'f>>,
+    ) -> MenuResult<Vec<String>> {
+        fields
+            .into_iter()
+            .map(|field| field.prompt_in(self))
+            .collect()
+    }
+
+    /// Prompts the given written fields on a compact layout, and returns the answers
+    /// of the user in order.
+    ///
+    /// Each field is displayed without the prefix, with its suffix `": "` on the same line,
+    /// for instance `"name: "`, so each answer only takes one line. The other specifications
+    /// are inherited from the global format, and the answers are recorded like with the
+    /// [`Values::written`] method.
+    ///
+    /// The answers are the inputs of the user, so they can be parsed afterward.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let answers = Values::default().inline_form(&[Written::from("name"), Written::from("age")])?;
+    /// # Ok(()) }
+    /// ```
+    pub fn inline_form(&mut self, fields: &[Written<'_>]) -> MenuResult<Vec<String>>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        self.show_banner()?;
+        let fmt = Format {
+            prefix: "",
+            suffix: ": ",
+            line_brk: false,
+            ..self.fmt.clone()
+        };
+        let mut answers = Vec::with_capacity(fields.len());
+        for written in fields {
+            answers.push(written.prompt_with(self.stream.deref_mut(), &fmt)?);
+            self.record_written(written);
+        }
+        Ok(answers)
+    }
+
+    /// Records the given answers of a form, tracking the fields that resolved to their
+    /// default value, and returns the records.
+    ///
+    /// The fields that have not been answered are skipped.
+    fn record_form(&mut self, answers: &[Option<FormAnswer>]) -> Vec<(String, String)> {
+        for answer in answers.iter().flatten() {
+            self.track_default(&answer.record.0, answer.used_default);
+            self.push_record(answer.record.clone());
+        }
+        answers.iter().flatten().map(|a| a.record.clone()).collect()
+    }
+
+    /// Prompts the given field of a form, and returns its answer, or `None` if the user
+    /// entered the back key.
+    ///
+    /// The previous answer of the field, if any, is its default value.
+    fn form_answer(
+        &mut self,
+        field: &FormField<'_, R, W>,
+        prev: Option<&FormAnswer>,
+    ) -> MenuResult<Option<FormAnswer>>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        let prev_default = |has_default: bool| prev.map_or(has_default, |p| p.used_default);
+        match field {
+            FormField::Written(written) => {
+                let mut written = (*written).clone().with_back_key(self.back_key);
+                if let Some(prev) = prev {
+                    written = written.default_value(&prev.answer);
+                }
+                let answer: String = written.prompt_with(self.stream.deref_mut(), &self.fmt)?;
+                if answer == self.back_key {
+                    self.stream.take_last_input();
+                    return Ok(None);
+                }
+                let input = self.stream.take_last_input();
+                Ok(Some(FormAnswer {
+                    used_default: written.used_default(input.as_deref()) && prev_default(true),
+                    record: written.recorded(input),
+                    answer,
+                }))
+            }
+            FormField::Selected(sel) => {
+                let prev_label = prev.map(|p| p.answer.as_str());
+                let label = sel.select_label(
+                    self.stream.deref_mut(),
+                    &self.fmt,
+                    prev_label,
+                    self.back_key,
+                )?;
+                let input = self.stream.take_last_input().unwrap_or_default();
+                Ok(label.map(|label| FormAnswer {
+                    used_default: input.is_empty() && prev_default(sel.has_default()),
+                    record: (sel.msg().to_owned(), label.clone()),
+                    answer: label,
+                }))
+            }
+        }
+    }
+
+    /// Prompts the given written field again if its message is among the given messages
+    /// of the fields that failed a validation, returning `Some(value)`, else `None`.
+    ///
+    /// It is useful to validate the answers of a form together, for instance with
+    /// constraints between the fields, and to prompt only the fields that failed again,
+    /// without restarting the whole form. The previous answer of the field is replaced
+    /// in the [records](Values::records), and in the [defaults](Values::defaults).
+    ///
+    /// See [`Values::written`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ezmenulib::prelude::*;
+    /// # fn main() -> MenuResult {
+    /// let start_field = Written::from("Start");
+    /// let end_field = Written::from("End");
+    ///
+    /// let mut menu = Values::default().record(true);
+    /// let mut start: u32 = menu.written(&start_field)?;
+    /// let mut end: u32 = menu.written(&end_field)?;
+    ///
+    /// loop {
+    ///     let failed = match start < end {
+    ///         true => break,
+    ///         false => ["Start", "End"],
+    ///     };
+    ///     if let Some(s) = menu.reask(&start_field, &failed)? {
+    ///         start = s;
+    ///     }
+    ///     if let Some(e) = menu.reask(&end_field, &failed)? {
+    ///         end = e;
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// If the given written field has an incorrect default value,
+    /// this function will panic at runtime.
+    pub fn reask<T>(&mut self, written: &Written<'_>, failed: &[&str]) -> MenuResult<Option<T>>
+    where
+        T: FromStr,
+    {
+        let msg = written.msg();
+        if !failed.contains(&msg) {
+            return Ok(None);
+        }
+
+        let pos = self
+            .records
+            .as_ref()
+            .and_then(|records| records.iter().position(|(m, _)| m == msg));
+        self.defaults.retain(|m| m != msg);
+
+        let out = self.written(written)?;
+
+        // Replaces the previous answer by the new one, which has been pushed at the end.
+        if let (Some(records), Some(pos)) = (self.records.as_mut(), pos) {
+            let record = records.pop().expect("the answer has just been recorded");
+            records[pos] = record;
+        }
+
+        Ok(Some(out))
     }
 
     /// Returns the next value written by the user by prompting him the field
@@ -289,7 +940,64 @@ where
         T: FromStr,
         F: Fn(&T) -> bool,
     {
-        written.prompt_until_with(self.stream.deref_mut(), til, &self.fmt)
+        self.show_banner()?;
+        let out = written.prompt_until_with(self.stream.deref_mut(), til, &self.fmt)?;
+        self.record_written(written);
+        Ok(out)
+    }
+
+    /// Returns the next value written by the user by prompting him the field
+    /// until the given asynchronous constraint is applied.
+    ///
+    /// It merges the [format](Format) of the field with the global format of the container.
+    /// The merge saves the custom formatting specification of the written field.
+    ///
+    /// See [`Written::prompt_until_async`] for more information.
+    ///
+    /// # Panic
+    ///
+    /// If the given written field has an incorrect default value,
+    /// this function will panic at runtime.
+    #[cfg(feature = "async")]
+    #[cfg_attr(nightly, doc(cfg(feature = "async")))]
+    pub async fn written_until_async<T, F, Fut>(
+        &mut self,
+        written: &Written<'_>,
+        til: F,
+    ) -> MenuResult<T>
+    where
+        T: FromStr,
+        F: Fn(&T) -> Fut,
+        Fut: std::future::Future<Output = bool>,
+    {
+        self.show_banner()?;
+        let out = written
+            .prompt_until_async_with(self.stream.deref_mut(), til, &self.fmt)
+            .await?;
+        self.record_written(written);
+        Ok(out)
+    }
+
+    /// Returns the next value written by the user, mapped with the `f` function.
+    ///
+    /// It merges the [format](Format) of the field with the global format of the container.
+    /// The merge saves the custom formatting specification of the written field.
+    ///
+    /// See [`Written::prompt_map`] for more information.
+    ///
+    /// # Panic
+    ///
+    /// If the given written field has an incorrect default value,
+    /// this function will panic at runtime.
+    pub fn written_map<T, U, E, F>(&mut self, written: &Written<'_>, f: F) -> MenuResult<U>
+    where
+        T: FromStr,
+        F: Fn(T) -> Result<U, E>,
+    {
+        self.show_banner()?;
+        let out = written.prompt_map_with(self.stream.deref_mut(), f, &self.fmt)?;
+        self.record_written(written);
+        Ok(out)
     }
 
     /// Returns the next value written by the user wrapped as `Some(value)`
@@ -308,7 +1016,10 @@ where
     where
         T: FromStr,
     {
-        written.optional_value_with(self.stream.deref_mut(), &self.fmt)
+        self.show_banner()?;
+        let out = written.optional_value_with(self.stream.deref_mut(), &self.fmt)?;
+        self.record_written(written);
+        Ok(out)
     }
 
     /// Returns the next many values written by the user wrapped as a `Vec<T>`, separated by
@@ -334,7 +1045,59 @@ where
         S: AsRef<str>,
         F: Fn(&T) -> bool,
     {
-        written.many_values_until_with(self.stream.deref_mut(), sep, til, &self.fmt)
+        self.show_banner()?;
+        let out = written.many_values_until_with(self.stream.deref_mut(), sep, til, &self.fmt)?;
+        self.record_written(written);
+        Ok(out)
+    }
+
+    /// Returns exactly `N` values written by the user, separated by `sep`.
+    ///
+    /// It merges the [format](Format) of the field with the global format of the container.
+    /// The merge saves the custom formatting specification of the written field.
+    ///
+    /// See [`Written::array_values`] for more information.
+    ///
+    /// # Panic
+    ///
+    /// If the given written field has an incorrect default value,
+    /// this function will panic at runtime.
+    pub fn written_array<T, S, const N: usize>(
+        &mut self,
+        written: &Written<'_>,
+        sep: S,
+    ) -> MenuResult<[T; N]>
+    where
+        T: FromStr,
+        S: AsRef<str>,
+    {
+        self.show_banner()?;
+        let out = written.array_values_with(self.stream.deref_mut(), sep, &self.fmt)?;
+        self.record_written(written);
+        Ok(out)
+    }
+
+    /// Returns the next many values written by the user wrapped as a `Vec<T>`,
+    /// one per line, until the user enters the `sentinel` token or an empty line.
+    ///
+    /// It merges the [format](Format) of the field with the global format of the container.
+    /// The merge saves the custom formatting specification of the written field.
+    /// The recorded answer contains the lines separated by commas.
+    ///
+    /// See [`Written::many_values_lines`] for more information.
+    pub fn many_written_lines<T>(
+        &mut self,
+        written: &Written<'_>,
+        sentinel: &str,
+    ) -> MenuResult<Vec<T>>
+    where
+        T: FromStr,
+    {
+        self.show_banner()?;
+        let (out, inputs) =
+            written.many_lines_inner(self.stream.deref_mut(), sentinel, &self.fmt)?;
+        self.push_record(written.recorded(Some(inputs.join(", "))));
+        Ok(out)
     }
 
     /// Returns the next many values written by the user wrapped as a `Vec<T>`,
@@ -354,7 +1117,37 @@ where
         T: FromStr,
         S: AsRef<str>,
     {
-        written.many_values_with(self.stream.deref_mut(), sep, &self.fmt)
+        self.show_banner()?;
+        let out = written.many_values_with(self.stream.deref_mut(), sep, &self.fmt)?;
+        self.record_written(written);
+        Ok(out)
+    }
+
+    /// Returns the next many values written by the user wrapped as a `Vec<T>`,
+    /// separated by `sep`, keeping the empty values.
+    ///
+    /// It merges the [format](Format) of the field with the global format of the container.
+    /// The merge saves the custom formatting specification of the written field.
+    ///
+    /// See [`Written::many_values_keep_empty`] for more information.
+    ///
+    /// # Panic
+    ///
+    /// If the given written field has an incorrect default value,
+    /// this function will panic at runtime.
+    pub fn many_written_keep_empty<T, S>(
+        &mut self,
+        written: &Written<'_>,
+        sep: S,
+    ) -> MenuResult<Vec<T>>
+    where
+        T: FromStr,
+        S: AsRef<str>,
+    {
+        self.show_banner()?;
+        let out = written.many_values_keep_empty_with(self.stream.deref_mut(), sep, &self.fmt)?;
+        self.record_written(written);
+        Ok(out)
     }
 
     /// Returns the next value written by the user, or the default value of the
@@ -373,7 +1166,65 @@ where
     where
         T: FromStr + Default,
     {
-        written.prompt_or_default_with(self.stream.deref_mut(), &self.fmt)
+        let _ = self.show_banner();
+        let out = written.prompt_or_default_with(self.stream.deref_mut(), &self.fmt);
+        self.record_written(written);
+        out
+    }
+
+    /// Displays the answers recorded by the container (see [`Values::record`]),
+    /// then asks the user to confirm them, and returns `true` if the user confirmed.
+    ///
+    /// The recorded answers are cleared, so the form can be prompted again
+    /// if the user declined:
+    ///
+    /// ```no_run
+    /// use ezmenulib::prelude::*;
+    ///
+    /// # fn main() -> MenuResult {
+    /// let mut values = Values::default().record(true);
+    /// let (name, age) = loop {
+    ///     let name: String = values.written(&Written::from("Name"))?;
+    ///     let age: u8 = values.written(&Written::from("Age"))?;
+    ///     if values.confirm_summary()? {
+    ///         break (name, age);
+    ///     }
+    /// };
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// The user confirms by entering `"y"` or `"yes"`, case-insensitively.
+    /// Any other input, or the end of the input stream, declines the answers.
+    pub fn confirm_summary(&mut self) -> MenuResult<bool> {
+        let records = self
+            .records
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default();
+        self.show_banner()?;
+        let stream = self.stream.deref_mut();
+
+        if !self.fmt.quiet {
+            for (msg, value) in records {
+                write!(
+                    stream,
+                    "{}{}: {}{}",
+                    self.fmt.prefix, msg, value, self.fmt.line_ending
+                )?;
+            }
+            write!(
+                stream,
+                "{}Confirm? [y/N]{}",
+                self.fmt.prefix, self.fmt.line_ending
+            )?;
+        }
+
+        let input = match prompt(&self.fmt, stream) {
+            Err(MenuError::Eof) => String::new(),
+            res => res?,
+        };
+        stream.take_last_input();
+        Ok(matches!(input.to_ascii_lowercase().as_str(), "y" | "yes"))
     }
 }
 
@@ -410,10 +1261,16 @@ where
 pub struct RawMenu<'a, R = In, W = Out> {
     /// The global format of the menu.
     pub fmt: Format<'a>,
-    title: Option<&'a str>,
+    title: Option<Title<'a>>,
+    prompt: Option<&'a str>,
+    footer: Option<&'a str>,
+    on_invalid: Option<InvalidHook<R, W>>,
     fields: Fields<'a, R, W>,
     stream: Mutable<'a, MenuStream<'a, R, W>>,
     once: bool,
+    timeout: Option<Duration>,
+    max_height: Option<usize>,
+    fit_terminal: bool,
 }
 
 impl<'a, R, W> UsesMutable<MenuStream<'a, R, W>> for RawMenu<'a, R, W> {
@@ -439,14 +1296,27 @@ impl<'a, R, W> UsesMutable<MenuStream<'a, R, W>> for RawMenu<'a, R, W> {
 impl<R, W> Display for RawMenu<'_, R, W> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         // Title
-        if let Some(title) = self.title {
-            f.write_str(title)?;
+        if let Some(ref title) = self.title {
+            f.write_str(&title.get())?;
         }
 
         // Fields
         // The chip representation is managed by the field itself.
         for (i, field) in self.fields.iter().enumerate() {
-            writeln!(f, "{}{}{}", i + 1, self.fmt.chip, field.0)?;
+            if self.fmt.show_index {
+                write!(
+                    f,
+                    "{}{}",
+                    padded_index(&self.fmt, i + 1, self.fields.len()),
+                    self.fmt.chip
+                )?;
+            }
+            write!(f, "{}{}", field.0, self.fmt.line_ending)?;
+        }
+
+        // Footer
+        if let Some(footer) = self.footer {
+            write!(f, "{}", Footer(&self.fmt, footer))?;
         }
 
         Ok(())
@@ -467,14 +1337,18 @@ impl<'a, const N: usize> From<&'a [Field<'a>; N]> for RawMenu<'a> {
 
 impl<'a, R, W> FromMutable<'a, MenuStream<'a, R, W>, Fields<'a, R, W>> for RawMenu<'a, R, W> {
     fn new(stream: Mutable<'a, MenuStream<'a, R, W>>, fields: Fields<'a, R, W>) -> Self {
-        check_fields(fields);
-
         Self {
             title: None,
+            prompt: None,
+            footer: None,
+            on_invalid: None,
             fmt: Format::default(),
             fields,
             stream,
             once: false,
+            timeout: None,
+            max_height: None,
+            fit_terminal: false,
         }
     }
 }
@@ -488,20 +1362,212 @@ impl<'a, R, W> RawMenu<'a, R, W> {
 
     /// Defines the title of the menu, which corresponds to the string slice displayed
     /// at the top when running the menu.
-    pub fn title(mut self, title: &'a str) -> Self {
-        self.title = Some(title);
+    ///
+    /// The title may be borrowed or owned, for instance to contain runtime values.
+    pub fn title<T: Into<Cow<'a, str>>>(mut self, title: T) -> Self {
+        self.set_title(title);
+        self
+    }
+
+    /// Defines the function returning the title of the menu.
+    ///
+    /// Unlike [`RawMenu::title`], the function is called each time the menu is displayed,
+    /// so the title can reflect the state modified by the mapped functions of the fields,
+    /// for instance `"Delete item (3 remaining)"`.
+    pub fn title_with(mut self, title: &'a dyn Fn() -> String) -> Self {
+        self.title = Some(Title::Dynamic(title));
+        self
+    }
+
+    /// Modifies the title of the menu, for instance between two runs.
+    ///
+    /// See [`RawMenu::title`] for more information.
+    pub fn set_title<T: Into<Cow<'a, str>>>(&mut self, title: T) {
+        self.title = Some(Title::Text(title.into()));
+    }
+
+    /// Defines the prompt of the menu, which corresponds to the string slice displayed
+    /// right before the user input, when selecting a field.
+    ///
+    /// It overrides the suffix of the global format (see [`Format::suffix`])
+    /// for this menu, for instance with `"Pick an action: "`. The nested menus
+    /// (see [`Kind::Parent`]) keep the suffix of the global format.
+    pub fn prompt(mut self, prompt: &'a str) -> Self {
+        self.prompt = Some(prompt);
+        self
+    }
+
+    /// Defines the footer of the menu, which corresponds to the string slice displayed
+    /// after the fields, before the user input.
+    ///
+    /// It is displayed with the prefix of the global format, like the title, and is useful
+    /// to show a usage hint, for instance `"Type the number of an action"`.
+    /// It is only displayed after the fields of this menu, not of its nested menus.
+    pub fn footer(mut self, footer: &'a str) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
+    /// Defines the function called when the user input does not match any field,
+    /// with the input and the stream.
+    ///
+    /// It replaces the message of the format used when the input is incorrect
+    /// (see [`Format::invalid_msg`]), for instance to suggest the closest label.
+    /// The user is then prompted again.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ezmenulib::prelude::*;
+    /// use std::io::Write;
+    ///
+    /// # fn main() -> MenuResult {
+    /// RawMenu::from(&[("Play", Kind::Map(&|_| Ok(()))), ("Quit", Kind::Quit)])
+    ///     .on_invalid(|input, stream| {
+    ///         writeln!(stream, "`{}` is not an action, type a number or a label", input)?;
+    ///         Ok(())
+    ///     })
+    ///     .run()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn on_invalid(mut self, on_invalid: InvalidHook<R, W>) -> Self {
+        self.on_invalid = Some(on_invalid);
         self
     }
 
     /// Defines if the menu should run once or loop when calling a mapped function
     /// to a field.
+    ///
+    /// By default, the menu loops: after calling a mapped function, it displays the fields
+    /// again, until the user selects a [`Kind::Quit`] field, or goes back from the top-level menu.
+    /// The mapped functions can then accumulate a state across the selections,
+    /// that the title may display with [`RawMenu::title_with`]:
+    ///
+    /// ```no_run
+    /// use ezmenulib::prelude::*;
+    /// use std::sync::Mutex;
+    ///
+    /// static ORDER: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+    ///
+    /// # fn main() -> MenuResult {
+    /// let title = || format!("Your order: {:?}", ORDER.lock().unwrap());
+    ///
+    /// RawMenu::from(&[
+    ///     ("Pizza", Kind::Map(&|_| Ok(ORDER.lock().unwrap().push("pizza")))),
+    ///     ("Salad", Kind::Map(&|_| Ok(ORDER.lock().unwrap().push("salad")))),
+    ///     ("Done", Kind::Quit),
+    /// ])
+    /// .title_with(&title)
+    /// .run()?;
+    /// # Ok(()) }
+    /// ```
     pub fn run_once(mut self, once: bool) -> Self {
         self.once = once;
         self
     }
+
+    /// Returns the fields of the menu.
+    ///
+    /// It allows to render the menu with another interface, using the same definition.
+    /// The sub-menus are reached with the [`Kind::children`] method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ezmenulib::prelude::*;
+    ///
+    /// fn print_tree(fields: Fields<'_>, depth: usize) {
+    ///     for (msg, kind) in fields {
+    ///         println!("{:indent$}{msg} ({kind:?})", "", indent = depth * 2);
+    ///         if let Some(children) = kind.children() {
+    ///             print_tree(children, depth + 1);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let menu = RawMenu::from(&[
+    ///     ("Play", Kind::Parent(&[("Solo", Kind::Quit), ("Back", Kind::Back(1))])),
+    ///     ("Quit", Kind::Quit),
+    /// ]);
+    /// print_tree(menu.fields(), 0);
+    /// ```
+    pub fn fields(&self) -> Fields<'a, R, W> {
+        self.fields
+    }
+
+    /// Defines the maximum idle time of the user when selecting a field of the menu.
+    ///
+    /// If the user takes more time than `timeout` to select a field, at any depth of the menu,
+    /// running the menu stops and returns a [`MenuError::Timeout`] error, so the caller
+    /// can restart it, for a kiosk for example.
+    ///
+    /// # Note
+    ///
+    /// Reading the user input is blocking, so the timeout is only checked once the user
+    /// entered an input: the menu does not stop by itself while it waits for the input,
+    /// and the late input is discarded. The prompts of the mapped functions are not concerned.
+    pub fn session_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Defines the maximum amount of fields displayed at once, at any depth of the menu.
+    ///
+    /// If the menu contains more fields, only a window of them is displayed, followed by
+    /// a line showing the displayed range. The user scrolls to the next fields by entering
+    /// `">"`, and to the previous fields by entering `"<"`. The indexes of the fields stay
+    /// the same, so the user can still select a field that is not displayed.
+    ///
+    /// # Panic
+    ///
+    /// If the maximum height is `0`, this function will panic.
+    pub fn max_height(mut self, height: usize) -> Self {
+        assert!(
+            height > 0,
+            "the maximum height of the menu must be positive"
+        );
+        self.max_height = Some(height);
+        self
+    }
+
+    /// Defines the maximum amount of fields displayed at once from the height of the terminal,
+    /// detected when running the menu, leaving room for the title, the footer and the prompt.
+    ///
+    /// The height is detected only with the `crossterm` feature, and if the standard output
+    /// is a terminal. Otherwise, the height given to [`RawMenu::max_height`] is used if any,
+    /// else all the fields are displayed.
+    ///
+    /// See [`RawMenu::max_height`] for more information about the scrolling.
+    pub fn fit_terminal(mut self) -> Self {
+        self.fit_terminal = true;
+        self
+    }
+}
+
+impl<'a, R, W> RawMenu<'a, R, W> {
+    /// Returns the format used when running the menu,
+    /// with the suffix overridden by the prompt of the menu if it is provided.
+    fn run_fmt(&self) -> Format<'a> {
+        Format {
+            suffix: self.prompt.unwrap_or(self.fmt.suffix),
+            ..self.fmt.clone()
+        }
+    }
+
+    /// Returns the maximum amount of fields displayed at once, if any
+    /// (see [`RawMenu::max_height`] and [`RawMenu::fit_terminal`]).
+    fn run_height(&self) -> Option<usize> {
+        let terminal = self
+            .fit_terminal
+            .then(terminal_height)
+            .flatten()
+            // The lines of the title, the footer, the scroll status and the prompt.
+            .map(|h| h.saturating_sub(4).max(1));
+        terminal.or(self.max_height)
+    }
 }
 
-impl<R, W> RawMenu<'_, R, W>
+impl<'a, R, W> RawMenu<'a, R, W>
 where
     R: BufRead,
     W: Write,
@@ -514,47 +1580,313 @@ where
     /// select a field. Then, it runs the corresponding procedure
     /// matching the selected field [kind](Kind).
     pub fn run(&mut self) -> MenuResult {
-        run_with(
-            &mut RunParams {
-                stream: self.stream.deref_mut(),
+        self.run_inner().0
+    }
+
+    /// Runs the menu, and returns its result with `true` if the input stream reached its end
+    /// while the user was selecting a field.
+    fn run_inner(&mut self) -> (MenuResult, bool) {
+        // Discards the output set during a previous run.
+        self.stream.clear_output();
+        let fmt = self.run_fmt();
+        let max_height = self.run_height();
+
+        let mut params = RunParams {
+            stream: self.stream.deref_mut(),
+            fmt: &fmt,
+            nested_fmt: &self.fmt,
+            once: self.once,
+            timeout: self.timeout,
+            footer: self.footer,
+            on_invalid: self.on_invalid,
+            max_height,
+            selection_eof: false,
+        };
+        let res = run_with(&mut params, self.title.as_ref(), self.fields).map(|_| ());
+        (res, params.selection_eof)
+    }
+
+    /// Runs the menu with the given inputs, instead of the inputs read by the stream.
+    ///
+    /// The inputs are given in order to the menu, and to the mapped functions reading
+    /// from the stream, as if the user entered them line by line. The menu runs like the
+    /// [`RawMenu::run`] method, and returns once the inputs are exhausted, or when the menu
+    /// is finished. The output is still written to the stream.
+    ///
+    /// The inputs may only run out while the menu prompts the user to select a field.
+    /// If a mapped function reaches the end of the inputs, it returns
+    /// a [`MenuError::Eof`] error.
+    ///
+    /// The inputs are fed through the reader of the stream, so the mapped functions reading
+    /// raw bytes from it also consume them. Once the menu returns, the stream reads
+    /// from its previous source again, including an enclosing script.
+    ///
+    /// It is useful to test a menu, or to run a predetermined sequence of selections.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ezmenulib::prelude::*;
+    ///
+    /// # fn main() -> MenuResult {
+    /// let mut menu = RawMenu::from(&[
+    ///     ("Add", Kind::Map(&|_| Ok(()))),
+    ///     ("Remove", Kind::Map(&|_| Ok(()))),
+    ///     ("Quit", Kind::Quit),
+    /// ]);
+    /// menu.run_scripted(&["1", "2", "quit"])?;
+    /// # Ok(()) }
+    /// ```
+    pub fn run_scripted(&mut self, inputs: &[&str]) -> MenuResult {
+        let script: String = inputs.iter().map(|s| format!("{}\n", s)).collect();
+        let prev = self
+            .stream
+            .replace_script(Some(Cursor::new(script.into_bytes())));
+        let (res, selection_eof) = self.run_inner();
+        self.stream.replace_script(prev);
+        // The inputs are exhausted only if the menu was waiting for the user to select
+        // a field. Otherwise, a mapped function lacked inputs.
+        match res {
+            Err(MenuError::Eof) if selection_eof => Ok(()),
+            res => res,
+        }
+    }
+
+    /// Runs the menu, and returns the value set by a mapped function.
+    ///
+    /// It runs the menu like the [`RawMenu::run`] method, until a mapped function sets
+    /// the output value with the [`MenuStream::set_output`] method, at any depth of the menu.
+    /// The menu then stops, and the value is returned.
+    ///
+    /// It returns `None` if the user quit the menu without any value set,
+    /// and a [`MenuError::Other`] error if the value set does not have the `T` type.
+    pub fn run_value<T: 'static>(&mut self) -> MenuResult<Option<T>> {
+        self.run()?;
+        self.stream.take_output()
+    }
+
+    /// Returns an iterator running the menu, yielding the message of each top-level
+    /// field selected by the user.
+    ///
+    /// Each iteration prints the menu, asks the user to select a field,
+    /// and runs its corresponding procedure, like the [`RawMenu::run`] method.
+    /// Only the [mapped](Kind::Map) and [parent](Kind::Parent) fields are yielded.
+    ///
+    /// The iteration ends when the user quits the menu, or goes back from the top-level menu.
+    /// If an error occurs, it is yielded and the iteration ends.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ezmenulib::prelude::*;
+    ///
+    /// # fn main() -> MenuResult {
+    /// let mut menu = RawMenu::from(&[
+    ///     ("Add", Kind::Map(&|_| Ok(()))),
+    ///     ("Remove", Kind::Map(&|_| Ok(()))),
+    ///     ("Quit", Kind::Quit),
+    /// ]);
+    ///
+    /// for action in menu.run_iter() {
+    ///     println!("the user selected {}", action?);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn run_iter(&mut self) -> RunIter<'_, 'a, R, W> {
+        RunIter {
+            fmt: self.run_fmt(),
+            menu: self,
+            done: false,
+        }
+    }
+}
+
+/// Iterator running a menu, and yielding the message of each top-level field
+/// selected by the user.
+///
+/// It is returned by the [`RawMenu::run_iter`] method.
+#[derive(Debug)]
+pub struct RunIter<'m, 'a, R, W> {
+    menu: &'m mut RawMenu<'a, R, W>,
+    fmt: Format<'a>,
+    done: bool,
+}
+
+impl<'a, R, W> Iterator for RunIter<'_, 'a, R, W>
+where
+    R: BufRead,
+    W: Write,
+{
+    type Item = MenuResult<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let max_height = self.menu.run_height();
+            let mut params = RunParams {
+                stream: self.menu.stream.deref_mut(),
                 fmt: &self.fmt,
-                once: self.once,
-            },
-            self.title,
-            self.fields,
-        )
-        .map(|_| ())
+                nested_fmt: &self.menu.fmt,
+                once: self.menu.once,
+                timeout: self.menu.timeout,
+                footer: self.menu.footer,
+                on_invalid: self.menu.on_invalid,
+                max_height,
+                selection_eof: false,
+            };
+
+            let title = self.menu.title.as_ref();
+            let (msg, kind) = match select_field(&mut params, title, self.menu.fields) {
+                Ok(field) => field,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            };
+
+            match handle_field(&mut params, msg, kind) {
+                Ok(depth) => self.done = !matches!(depth, Depth::Current),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+
+            if let Kind::Map(_) | Kind::Parent(_) = kind {
+                return Some(Ok(msg));
+            }
+        }
+
+        None
+    }
+}
+
+/// Represents the title of a menu.
+enum Title<'a> {
+    /// The text of the title, borrowed or owned.
+    Text(Cow<'a, str>),
+    /// The function returning the title, called each time the menu is displayed.
+    Dynamic(&'a dyn Fn() -> String),
+}
+
+impl Title<'_> {
+    /// Returns the text of the title.
+    fn get(&self) -> Cow<'_, str> {
+        match self {
+            Self::Text(s) => Cow::Borrowed(s),
+            Self::Dynamic(f) => Cow::Owned(f()),
+        }
     }
 }
 
+impl fmt::Debug for Title<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(s) => f.debug_tuple("Text").field(s).finish(),
+            Self::Dynamic(_) => f.write_str("Dynamic"),
+        }
+    }
+}
+
+/// The answer of a field of a form (see [`Values::form`]).
+#[derive(Clone)]
+struct FormAnswer {
+    /// The answer returned to the caller, as entered by the user.
+    answer: String,
+    /// The message of the field with its recorded answer, masked for a secret field.
+    record: (String, String),
+    /// Whether the field resolved to its default value.
+    used_default: bool,
+}
+
 /// Represents the parameters of the menu currently running, which are the same
 /// at any state of the menu (any depth of the `run_with` recursive function).
 struct RunParams<'a, 'b: 'a, R, W> {
     stream: &'a mut MenuStream<'b, R, W>,
     fmt: &'a Format<'b>,
+    /// The format of the nested menus, without the prompt of the menu.
+    nested_fmt: &'a Format<'b>,
     once: bool,
+    timeout: Option<Duration>,
+    footer: Option<&'b str>,
+    on_invalid: Option<InvalidHook<R, W>>,
+    max_height: Option<usize>,
+    /// `true` if the input stream reached its end while the user was selecting a field.
+    selection_eof: bool,
+}
+
+/// The footer of a menu, displayed with the prefix of the given format.
+///
+/// It is shared by the [`Display`] implementation of the menu and the running menu,
+/// so both render it the same way.
+struct Footer<'a, 'b>(&'a Format<'b>, &'a str);
+
+impl Display for Footer<'_, '_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.0.prefix, self.1, self.0.line_ending)
+    }
 }
 
 /// Prints out the menu to the terminal.
+///
+/// If the menu has a maximum height, only the window of fields starting
+/// at the `offset` index is displayed, followed by the displayed range.
 fn show_menu<R, W: Write>(
     params: &mut RunParams<R, W>,
     msg: Option<&str>,
     fields: Fields<R, W>,
+    offset: usize,
 ) -> MenuResult {
+    if params.fmt.quiet {
+        return Ok(());
+    }
+
     // Title of current selective menu.
     if let Some(s) = msg {
-        writeln!(params.stream, "{}{s}", params.fmt.prefix)?;
+        write!(
+            params.stream,
+            "{}{}{}",
+            params.fmt.prefix,
+            params.fmt.decorated(s),
+            params.fmt.line_ending
+        )?;
     }
 
     // Fields of current selective menu.
-    for (i, (field_msg, _)) in (1..=fields.len()).zip(fields.iter()) {
-        writeln!(
+    let end = params
+        .max_height
+        .map_or(fields.len(), |h| (offset + h).min(fields.len()));
+    for (i, (field_msg, _)) in (offset + 1..=end).zip(fields[offset..end].iter()) {
+        if params.fmt.show_index {
+            write!(
+                params.stream,
+                "{}{}{}{}",
+                params.fmt.left_sur,
+                padded_index(params.fmt, i, fields.len()),
+                params.fmt.right_sur,
+                params.fmt.chip
+            )?;
+        }
+        write!(params.stream, "{field_msg}{}", params.fmt.line_ending)?;
+    }
+
+    // Scroll status of the current selective menu.
+    if end - offset < fields.len() {
+        write!(
             params.stream,
-            "{}{i}{}{}{field_msg}",
-            params.fmt.left_sur, params.fmt.right_sur, params.fmt.chip
+            "{}{}-{} of {} (`>` next, `<` previous){}",
+            params.fmt.prefix,
+            offset + 1,
+            end,
+            fields.len(),
+            params.fmt.line_ending
         )?;
     }
 
+    // Footer of the current selective menu.
+    if let Some(s) = params.footer {
+        write!(params.stream, "{}", Footer(params.fmt, s))?;
+    }
+
     Ok(())
 }
 
@@ -569,23 +1901,83 @@ fn handle_field<R: BufRead, W: Write>(
     Ok(match kind {
         Kind::Map(f) => {
             f(params.stream)?;
-            if params.once {
+            if params.once || params.stream.has_output() {
                 Quit
             } else {
                 Current
             }
         }
-        Kind::Parent(fields) => match run_with(params, Some(msg), fields)? {
-            Current | Back(0) => Current,
-            Quit => Quit,
-            Back(i) => Back(i - 1),
-        },
+        Kind::Parent(fields) => {
+            // The prompt and the footer of the menu are not inherited by its nested menus.
+            let fmt = std::mem::replace(&mut params.fmt, params.nested_fmt);
+            let footer = params.footer.take();
+            let depth = run_with(params, Some(&Title::Text(msg.into())), fields);
+            params.fmt = fmt;
+            params.footer = footer;
+            match depth? {
+                Current | Back(0) => Current,
+                Quit => Quit,
+                Back(i) => Back(i - 1),
+            }
+        }
         Kind::Back(0) => Current,
         Kind::Back(i) => Back(i - 1),
         Kind::Quit => Quit,
     })
 }
 
+/// Prints out the menu to the terminal, then returns the field selected by the user.
+fn select_field<'f, R: BufRead, W: Write>(
+    params: &mut RunParams<R, W>,
+    msg: Option<&Title>,
+    fields: Fields<'f, R, W>,
+) -> MenuResult<&'f Field<'f, R, W>> {
+    check_fields(fields)?;
+    let msg = msg.map(Title::get);
+    let mut offset = 0;
+    show_menu(params, msg.as_deref(), fields, offset)?;
+
+    let labels: Vec<&str> = fields.iter().map(|(msg, _)| *msg).collect();
+    loop {
+        let start = Instant::now();
+        let selected = match select(params.stream, params.fmt, &labels, &[], &[], None) {
+            Err(MenuError::Eof) => {
+                params.selection_eof = true;
+                return Err(MenuError::Eof);
+            }
+            res => res?,
+        };
+        match params.timeout {
+            Some(timeout) if start.elapsed() > timeout => return Err(MenuError::Timeout),
+            _ => (),
+        }
+
+        // Scrolls the window of fields if the menu has a maximum height.
+        if let (None, Some(h)) = (selected, params.max_height) {
+            let scrolled = match params.stream.last_input().map(str::trim) {
+                Some(">") => Some((offset + h).min(fields.len().saturating_sub(h))),
+                Some("<") => Some(offset.saturating_sub(h)),
+                _ => None,
+            };
+            if let Some(scrolled) = scrolled {
+                params.stream.take_last_input();
+                offset = scrolled;
+                show_menu(params, msg.as_deref(), fields, offset)?;
+                continue;
+            }
+        }
+
+        match (selected.and_then(|i| fields.get(i)), params.on_invalid) {
+            (Some(field), _) => return Ok(field),
+            (None, Some(f)) => {
+                let input = params.stream.take_last_input().unwrap_or_default();
+                f(&input, params.stream)?;
+            }
+            (None, None) => show_invalid(params.fmt, params.stream)?,
+        }
+    }
+}
+
 /// Recursive function used to run the current prompt state of the menu.
 ///
 /// It prints out to the stream the fields next to their indexes, then asks the user to
@@ -596,21 +1988,11 @@ fn handle_field<R: BufRead, W: Write>(
 /// level from the current running prompt.
 fn run_with<R: BufRead, W: Write>(
     params: &mut RunParams<R, W>,
-    msg: Option<&str>,
+    msg: Option<&Title>,
     fields: Fields<R, W>,
 ) -> MenuResult<Depth> {
     loop {
-        show_menu(params, msg, fields)?;
-
-        // Gets the message and the field kind selected by the user.
-        let (msg, kind) = loop {
-            match select(params.stream, params.fmt.suffix, fields.len())?
-                .and_then(|i| fields.get(i))
-            {
-                Some(field) => break field,
-                None => continue,
-            }
-        };
+        let (msg, kind) = select_field(params, msg, fields)?;
 
         match handle_field(params, msg, kind)? {
             Depth::Quit => return Ok(Depth::Quit),
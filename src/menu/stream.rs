@@ -1,7 +1,18 @@
+use crate::{MenuError, MenuResult};
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::VecDeque;
 use std::fmt;
 use std::fmt::Arguments;
-use std::io::{self, stdin, stdout, BufRead, BufReader, IoSlice, IoSliceMut, Read, Write};
+use std::io::{
+    self, stdin, stdout, BufRead, BufReader, Cursor, IoSlice, IoSliceMut, IsTerminal, Read, Write,
+};
 use std::ops::{Deref, DerefMut};
+use std::time::{Duration, Instant};
+
+/// The maximum delay between two lines read by the stream
+/// to consider the second one as pending, if it is empty.
+const DRAIN_DELAY: Duration = Duration::from_millis(20);
 
 macro_rules! map_impl {
     (
@@ -14,6 +25,17 @@ macro_rules! map_impl {
             self.$target.$name($($arg),*)
         }
     )*};
+
+    (
+        $target:ident(),
+        $($name:ident($($arg:ident: $ty:ty),*)$( -> $ret:ty)?),*
+        $(,)?
+    ) => {$(
+        #[inline]
+        fn $name(&mut self, $($arg: $ty),*) $(-> $ret)? {
+            self.$target().$name($($arg),*)
+        }
+    )*};
 }
 
 /// Represents a mutable object in the library.
@@ -42,6 +64,28 @@ impl<T: Default> Default for Mutable<'_, T> {
 }
 
 impl<T> Mutable<'_, T> {
+    /// Returns `true` if the inner object is [owned](Mutable::Owned).
+    pub fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+
+    /// Returns an owned version of the mutable object, consuming `self`.
+    ///
+    /// If the inner object is [borrowed](Mutable::Borrowed), it is taken from the borrow,
+    /// and replaced by its default value, so the returned object is not bound
+    /// to the lifetime of the borrow anymore. For instance, a borrowed [`MenuStream`]
+    /// using the standard input and output is taken, with its buffered input,
+    /// and replaced by a new one.
+    pub fn into_owned<'b>(self) -> Mutable<'b, T>
+    where
+        T: Default,
+    {
+        match self {
+            Self::Owned(t) => Mutable::Owned(t),
+            Self::Borrowed(t) => Mutable::Owned(std::mem::take(t)),
+        }
+    }
+
     /// Returns the inner object **if it is owned**, consuming `self`.
     ///
     /// # Panics
@@ -120,6 +164,29 @@ impl<T> DerefMut for Mutable<'_, T> {
 /// Although, you cannot wrap and instantiate if the stream does not own the reader,
 /// because `BufReader` needs to own it.
 ///
+/// ## Bidirectional handles
+///
+/// A handle that is both the reader and the writer, such as a [`TcpStream`](std::net::TcpStream),
+/// can be shared with the [`MenuStream::shared`] method, if its reference implements
+/// `Read` and `Write`. The generic types of the stream are then spelled as
+/// `MenuStream<BufReader<&TcpStream>, &TcpStream>`. This is useful to serve a menu
+/// over a network connection:
+///
+/// ```no_run
+/// use std::io::BufReader;
+/// use std::net::{TcpListener, TcpStream};
+/// use ezmenulib::prelude::*;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let listener = TcpListener::bind("127.0.0.1:8080")?;
+/// for conn in listener.incoming() {
+///     let conn = conn?;
+///     let mut stream: MenuStream<BufReader<&TcpStream>, &TcpStream> = MenuStream::shared(&conn);
+///     let name: String = Written::from("What is your name?").prompt(&mut stream)?;
+/// }
+/// # Ok(()) }
+/// ```
+///
 /// ## Inheritance
 ///
 /// You may give a mutable reference to the streams instead of giving the ownership.
@@ -138,8 +205,17 @@ impl<T> DerefMut for Mutable<'_, T> {
 pub struct MenuStream<'a, R = super::In, W = super::Out> {
     reader: Mutable<'a, R>,
     writer: Mutable<'a, W>,
+    drain: Option<Duration>,
+    last_read: Option<Instant>,
+    last_input: Option<String>,
+    output: Option<Box<dyn Any + Send>>,
+    on_read: Option<fn(String) -> String>,
+    context: Option<Box<dyn Any + Send>>,
+    script: Option<Cursor<Vec<u8>>>,
 }
 
+/// Returns the stream using the standard input, wrapped with a [`BufReader`] of the default
+/// capacity (8 KiB), and the standard output.
 impl Default for MenuStream<'_> {
     #[inline]
     fn default() -> Self {
@@ -147,12 +223,57 @@ impl Default for MenuStream<'_> {
     }
 }
 
+impl MenuStream<'_> {
+    /// Instantiates the stream using the standard input, wrapped with a [`BufReader`]
+    /// of the given capacity in bytes, and the standard output.
+    ///
+    /// The default capacity is 8 KiB (see [`MenuStream::default`]). A larger capacity
+    /// reads very large inputs, such as pasted tokens or documents, with fewer system calls.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ezmenulib::prelude::*;
+    ///
+    /// let mut stream = MenuStream::with_capacity(1 << 20);
+    /// let token: String = Written::from("Paste the token").prompt(&mut stream).unwrap();
+    /// ```
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::wrap_reader_with_capacity(capacity, stdin(), stdout())
+    }
+}
+
 impl<R: Read, W> MenuStream<'_, BufReader<R>, W> {
     /// Instantiate the stream by wrapping the reader with a [`BufReader`].
     #[inline]
     pub fn wrap_reader(reader: R, writer: W) -> Self {
         Self::new(BufReader::new(reader), writer)
     }
+
+    /// Instantiate the stream by wrapping the reader with a [`BufReader`]
+    /// of the given capacity in bytes.
+    ///
+    /// See [`MenuStream::with_capacity`] for more information.
+    #[inline]
+    pub fn wrap_reader_with_capacity(capacity: usize, reader: R, writer: W) -> Self {
+        Self::new(BufReader::with_capacity(capacity, reader), writer)
+    }
+}
+
+impl<'h, H> MenuStream<'_, BufReader<&'h H>, &'h H>
+where
+    &'h H: Read + Write,
+{
+    /// Instantiate the stream by sharing the given handle as the reader, wrapped with
+    /// a [`BufReader`], and as the writer.
+    ///
+    /// See the [bidirectional handles](MenuStream#bidirectional-handles) section
+    /// for more information.
+    #[inline]
+    pub fn shared(handle: &'h H) -> Self {
+        Self::wrap_reader(handle, handle)
+    }
 }
 
 impl<'a, R, W> MenuStream<'a, R, W> {
@@ -161,6 +282,13 @@ impl<'a, R, W> MenuStream<'a, R, W> {
         Self {
             reader: Mutable::Owned(reader),
             writer: Mutable::Owned(writer),
+            drain: None,
+            last_read: None,
+            last_input: None,
+            output: None,
+            on_read: None,
+            context: None,
+            script: None,
         }
     }
 
@@ -169,9 +297,251 @@ impl<'a, R, W> MenuStream<'a, R, W> {
         Self {
             reader: Mutable::Borrowed(reader),
             writer: Mutable::Borrowed(writer),
+            drain: None,
+            last_read: None,
+            last_input: None,
+            output: None,
+            on_read: None,
+            context: None,
+            script: None,
+        }
+    }
+
+    /// Defines if the stream drains the pending empty lines before reading
+    /// the user input (`false` by default).
+    ///
+    /// On some terminals, a paste or a fast keypress sends two line breaks, and the second
+    /// empty line is consumed by the next prompt, for instance resulting in its default value.
+    /// If the stream drains the pending lines, an empty line read right after
+    /// the previous line is ignored.
+    ///
+    /// It only applies if the standard input is a terminal. With a piped input,
+    /// all the lines are read at once and the empty lines may be intentional,
+    /// so it has no effect.
+    pub fn drain_pending(mut self, drain: bool) -> Self {
+        self.drain = (drain && stdin().is_terminal()).then_some(DRAIN_DELAY);
+        self
+    }
+
+    /// Drains the empty lines read within the given delay after the previous line,
+    /// whatever the standard input is.
+    #[cfg(test)]
+    pub(crate) fn drain_within(mut self, delay: Duration) -> Self {
+        self.drain = Some(delay);
+        self
+    }
+
+    /// Defines the function preprocessing each line read by the stream as the user input,
+    /// whatever the field prompted.
+    ///
+    /// It is applied to the trimmed line, before the input is handled by the field,
+    /// for instance to expand the environment variables, or to redact the secrets
+    /// before logging the input. It is a global version of the
+    /// [`Written::transform`](crate::field::Written::transform) method.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ezmenulib::prelude::*;
+    ///
+    /// let mut stream = MenuStream::default().on_read(|s| {
+    ///     match std::env::var("HOME") {
+    ///         Ok(home) => s.replace("$HOME", &home),
+    ///         Err(_) => s,
+    ///     }
+    /// });
+    /// let path: String = Written::from("Path").prompt(&mut stream).unwrap();
+    /// ```
+    pub fn on_read(mut self, on_read: fn(String) -> String) -> Self {
+        self.on_read = Some(on_read);
+        self
+    }
+
+    /// Returns the given line preprocessed by the stream (see [`MenuStream::on_read`]).
+    pub(crate) fn preprocessed(&self, line: String) -> String {
+        match self.on_read {
+            Some(f) => f(line),
+            None => line,
+        }
+    }
+
+    /// Registers a line read by the stream, and returns `true` if it is a pending empty line
+    /// to drain.
+    pub(crate) fn drained(&mut self, empty: bool) -> bool {
+        let now = Instant::now();
+        let pending = match (self.drain, self.last_read) {
+            (Some(delay), Some(t)) => empty && now.duration_since(t) < delay,
+            _ => false,
+        };
+        self.last_read = Some(now);
+        pending
+    }
+
+    /// Returns a mutable reference to the reader of the stream.
+    ///
+    /// Reading directly from the reader bypasses the options of the stream,
+    /// such as [draining the pending lines](MenuStream::drain_pending).
+    #[inline]
+    pub fn reader_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns a mutable reference to the writer of the stream.
+    ///
+    /// It is useful to write an output with a custom formatting between two prompts.
+    ///
+    /// # Note
+    ///
+    /// Writing directly to the writer may interfere with the state of the terminal
+    /// handled by the menu, such as the colors or a cleared screen, if these features are used.
+    #[inline]
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Sets the output value of the menu currently running, then quits the menu
+    /// once the mapped function returns.
+    ///
+    /// It is meant to be called by the mapped functions of a menu run with
+    /// [`RawMenu::run_value`](crate::menu::RawMenu::run_value), which returns the value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ezmenulib::prelude::*;
+    ///
+    /// # fn main() -> MenuResult {
+    /// let level: Option<u8> = RawMenu::from(&[
+    ///     ("Easy", Kind::Map(&|s| Ok(s.set_output(1u8)))),
+    ///     ("Hard", Kind::Map(&|s| Ok(s.set_output(3u8)))),
+    ///     ("Quit", Kind::Quit),
+    /// ])
+    /// .run_value()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn set_output<T: Send + 'static>(&mut self, output: T) {
+        self.output = Some(Box::new(output));
+    }
+
+    /// Attaches the given context to the stream, replacing the previous one.
+    ///
+    /// The context is a value owned by the stream, that the mapped functions of a menu
+    /// can access with the [`MenuStream::context_mut`] method, because they receive the stream.
+    /// It is useful to share the answers retrieved earlier with a [`Values`](crate::menu::Values)
+    /// container, such as the name of a project, with the actions of a menu.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ezmenulib::prelude::*;
+    /// use std::io::Write;
+    ///
+    /// struct Project {
+    ///     name: String,
+    /// }
+    ///
+    /// # fn main() -> MenuResult {
+    /// let mut stream = MenuStream::default();
+    /// let name: String = Written::from("Project name").prompt(&mut stream)?;
+    /// stream.set_context(Project { name });
+    ///
+    /// RawMenu::borrowed(&mut stream, &[
+    ///     ("Build", Kind::Map(&|s| {
+    ///         let name = match s.context_mut::<Project>() {
+    ///             Some(project) => project.name.clone(),
+    ///             None => return Ok(()),
+    ///         };
+    ///         writeln!(s, "building {}...", name)?;
+    ///         Ok(())
+    ///     })),
+    ///     ("Quit", Kind::Quit),
+    /// ])
+    /// .run()?;
+    /// # Ok(()) }
+    /// ```
+    pub fn set_context<T: Send + 'static>(&mut self, context: T) {
+        self.context = Some(Box::new(context));
+    }
+
+    /// Returns a reference to the context attached to the stream,
+    /// or `None` if there is no context or if it does not have the `T` type.
+    ///
+    /// See [`MenuStream::set_context`] for more information.
+    pub fn context<T: 'static>(&self) -> Option<&T> {
+        self.context.as_ref()?.downcast_ref()
+    }
+
+    /// Returns a mutable reference to the context attached to the stream,
+    /// or `None` if there is no context or if it does not have the `T` type.
+    ///
+    /// See [`MenuStream::set_context`] for more information.
+    pub fn context_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.context.as_mut()?.downcast_mut()
+    }
+
+    /// Returns the context attached to the stream if it has the `T` type, and removes it.
+    pub fn take_context<T: 'static>(&mut self) -> Option<T> {
+        match self.context.take()?.downcast() {
+            Ok(context) => Some(*context),
+            Err(other) => {
+                self.context = Some(other);
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if a mapped function has set the output value of the menu.
+    pub(crate) fn has_output(&self) -> bool {
+        self.output.is_some()
+    }
+
+    /// Returns the output value of the menu, if any, and resets it.
+    ///
+    /// It returns a [`MenuError::Other`] error if the output value does not have the `T` type.
+    pub(crate) fn take_output<T: 'static>(&mut self) -> MenuResult<Option<T>> {
+        match self.output.take() {
+            Some(output) => match output.downcast() {
+                Ok(output) => Ok(Some(*output)),
+                Err(_) => Err(MenuError::from(format!(
+                    "the output value of the menu does not have the `{}` type",
+                    std::any::type_name::<T>()
+                ))),
+            },
+            None => Ok(None),
         }
     }
 
+    /// Discards the output value of the menu, if any.
+    pub(crate) fn clear_output(&mut self) {
+        self.output = None;
+    }
+
+    /// Saves the last input read by the stream.
+    pub(crate) fn set_last_input(&mut self, input: &str) {
+        self.last_input = Some(input.to_owned());
+    }
+
+    /// Returns the last input read by the stream, if any, without resetting it.
+    pub(crate) fn last_input(&self) -> Option<&str> {
+        self.last_input.as_deref()
+    }
+
+    /// Returns the last input read by the stream, if any, and resets it.
+    pub(crate) fn take_last_input(&mut self) -> Option<String> {
+        self.last_input.take()
+    }
+
+    /// Defines the lines read by the stream instead of the reader, if any,
+    /// and returns the previous ones.
+    ///
+    /// Once the lines are exhausted, the stream reads as if it reached its end.
+    pub(crate) fn replace_script(
+        &mut self,
+        script: Option<Cursor<Vec<u8>>>,
+    ) -> Option<Cursor<Vec<u8>>> {
+        std::mem::replace(&mut self.script, script)
+    }
+
     /// Retrieves the reader and writer of the stream.
     ///
     /// ## Panics
@@ -184,9 +554,29 @@ impl<'a, R, W> MenuStream<'a, R, W> {
     }
 }
 
+impl<R: Read, W> MenuStream<'_, R, W> {
+    /// Returns the scripted lines if the stream is scripted, otherwise the reader.
+    fn input(&mut self) -> &mut dyn Read {
+        match self.script {
+            Some(ref mut script) => script,
+            None => &mut *self.reader,
+        }
+    }
+}
+
+impl<R: BufRead, W> MenuStream<'_, R, W> {
+    /// Returns the scripted lines if the stream is scripted, otherwise the reader.
+    fn buf_input(&mut self) -> &mut dyn BufRead {
+        match self.script {
+            Some(ref mut script) => script,
+            None => &mut *self.reader,
+        }
+    }
+}
+
 impl<R: Read, W> Read for MenuStream<'_, R, W> {
     map_impl!(
-        reader,
+        input(),
         read(buf: &mut [u8]) -> io::Result<usize>,
         read_vectored(bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize>,
         read_to_end(buf: &mut Vec<u8>) -> io::Result<usize>,
@@ -197,7 +587,7 @@ impl<R: Read, W> Read for MenuStream<'_, R, W> {
 
 impl<R: BufRead, W> BufRead for MenuStream<'_, R, W> {
     map_impl!(
-        reader,
+        buf_input(),
         fill_buf() -> io::Result<&[u8]>,
         consume(amt: usize),
         read_until(byte: u8, buf: &mut Vec<u8>) -> io::Result<usize>,
@@ -218,9 +608,277 @@ impl<R, W: Write> Write for MenuStream<'_, R, W> {
 
 impl<R, W: Write> fmt::Write for MenuStream<'_, R, W> {
     fn write_str(&mut self, s: &str) -> fmt::Result {
-        self.writer
-            .write_all(s.as_bytes())
-            .and(self.writer.flush())
-            .map_err(|_| fmt::Error)
+        self.writer.write_all(s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
+/// Writer wrapper that strips the ANSI escape sequences from the bytes written through it.
+///
+/// It filters the CSI sequences (including the SGR ones used for colors and styles),
+/// and the other escape sequences (such as the character set selection). It is useful to record the output of a menu
+/// into a log file, without keeping the escape codes meant for the terminal.
+///
+/// ## Example
+///
+/// ```
+/// use std::io::Write;
+/// use ezmenulib::menu::StripAnsi;
+///
+/// let mut log = StripAnsi::new(Vec::new());
+/// write!(log, "\x1b[1;31mhello\x1b[0m").unwrap();
+/// assert_eq!(log.into_inner(), b"hello");
+/// ```
+#[derive(Debug)]
+pub struct StripAnsi<W> {
+    inner: W,
+    state: AnsiState,
+}
+
+/// The state of the escape sequence currently parsed by the [`StripAnsi`] writer.
+#[derive(Debug, Clone, Copy)]
+enum AnsiState {
+    /// Plain text, passed through the writer.
+    Text,
+    /// Right after the `ESC` byte.
+    Escape,
+    /// Inside a CSI sequence (`ESC [`), until its final byte.
+    Csi,
+}
+
+impl<W> StripAnsi<W> {
+    /// Wraps the given writer.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            state: AnsiState::Text,
+        }
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming `self`.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for StripAnsi<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut out = Vec::with_capacity(buf.len());
+        for &b in buf {
+            self.state = match (self.state, b) {
+                (AnsiState::Text, 0x1b) => AnsiState::Escape,
+                (AnsiState::Text, b) => {
+                    out.push(b);
+                    AnsiState::Text
+                }
+                (AnsiState::Escape, b'[') => AnsiState::Csi,
+                // Intermediate bytes of the escape sequence.
+                (AnsiState::Escape, 0x20..=0x2f) => AnsiState::Escape,
+                (AnsiState::Escape, _) => AnsiState::Text,
+                (AnsiState::Csi, 0x40..=0x7e) => AnsiState::Text,
+                (AnsiState::Csi, _) => AnsiState::Csi,
+            };
+        }
+        self.inner.write_all(&out)?;
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
     }
 }
+
+/// Writer wrapper that keeps the most recent lines written through it.
+///
+/// The bytes are written to the inner writer, and the last complete lines are kept
+/// in a bounded buffer, up to the given capacity. It is useful to display a panel
+/// of the recent output of a menu, for instance in a status area.
+/// Like the other writer wrappers, it can be composed, such as `RingWriter<StripAnsi<W>>`.
+///
+/// ## Example
+///
+/// ```
+/// use std::io::Write;
+/// use ezmenulib::menu::RingWriter;
+///
+/// let mut recent = RingWriter::new(Vec::new(), 2);
+/// write!(recent, "one\ntwo\nthree\nfour").unwrap();
+/// assert!(recent.lines().eq(["two", "three"]));
+/// assert_eq!(recent.partial(), "four");
+/// ```
+#[derive(Debug)]
+pub struct RingWriter<W> {
+    inner: W,
+    capacity: usize,
+    lines: VecDeque<String>,
+    partial: Vec<u8>,
+}
+
+impl<W> RingWriter<W> {
+    /// Wraps the given writer, keeping at most `capacity` lines.
+    pub fn new(inner: W, capacity: usize) -> Self {
+        Self {
+            inner,
+            capacity,
+            lines: VecDeque::with_capacity(capacity),
+            partial: Vec::new(),
+        }
+    }
+
+    /// Returns the most recent complete lines, from the oldest to the newest,
+    /// without their line break.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(String::as_str)
+    }
+
+    /// Returns the last line written, if it is not complete yet, such as a prompt.
+    pub fn partial(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.partial)
+    }
+
+    /// Clears the kept lines.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.partial.clear();
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming `self`.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Keeps the given complete line, dropping the oldest one if the buffer is full.
+    fn push_line(&mut self, line: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+}
+
+impl<W: Write> Write for RingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        for &b in &buf[..n] {
+            match b {
+                b'\n' => {
+                    let line = String::from_utf8_lossy(&self.partial)
+                        .trim_end_matches('\r')
+                        .to_owned();
+                    self.partial.clear();
+                    self.push_line(line);
+                }
+                b => self.partial.push(b),
+            }
+        }
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Runs the given closure with a stream reading the scripted `input`, and asserts that
+/// the `expects` strings appear in order in the output written by the closure.
+///
+/// The expected strings may be any part of the output, such as the messages of the prompts.
+/// It returns the output of the closure, so the values retrieved from the stream can be
+/// checked as well. The [`assert_menu!`](crate::assert_menu) macro is a shortcut for it.
+///
+/// ## Panics
+///
+/// If an expected string is not found in the output after the previous one, this function
+/// will panic, showing the whole output.
+///
+/// ## Example
+///
+/// ```
+/// use ezmenulib::prelude::*;
+/// use ezmenulib::menu::assert_prompts;
+///
+/// let age: u8 = assert_prompts(
+///     "Ahmad\n19\n",
+///     &["name", "age"],
+///     |stream| {
+///         let mut values = Values::from(stream);
+///         let _: String = values.written(&Written::from("name"))?;
+///         values.written(&Written::from("age"))
+///     },
+/// )
+/// .unwrap();
+/// assert_eq!(age, 19);
+/// ```
+pub fn assert_prompts<T, F>(input: &str, expects: &[&str], f: F) -> MenuResult<T>
+where
+    F: FnOnce(MenuStream<'_, &[u8], Vec<u8>>) -> MenuResult<T>,
+{
+    let mut reader = input.as_bytes();
+    let mut output = Vec::new();
+    let out = f(MenuStream::with(&mut reader, &mut output));
+
+    let output = String::from_utf8_lossy(&output);
+    let mut pos = 0;
+    for e in expects {
+        match output[pos..].find(e) {
+            Some(i) => pos += i + e.len(),
+            None => panic!(
+                "`{}` not found in order in the output of the menu:\n{}",
+                e, output
+            ),
+        }
+    }
+
+    out
+}
+
+/// Runs a closure with a scripted input, and asserts that the given strings appear
+/// in order in its output.
+///
+/// It expands to a call to [`assert_prompts`](crate::menu::assert_prompts),
+/// and evaluates to its output.
+///
+/// ## Example
+///
+/// ```
+/// use ezmenulib::prelude::*;
+/// use ezmenulib::assert_menu;
+///
+/// let name: String = assert_menu!(
+///     "Ahmad\n",
+///     |mut stream| Written::from("What is your name").prompt(&mut stream),
+///     expects: ["What is your name", ">> "],
+/// )
+/// .unwrap();
+/// assert_eq!(name, "Ahmad");
+/// ```
+#[macro_export]
+macro_rules! assert_menu {
+    ($input:expr, $f:expr, expects: [$($e:expr),* $(,)?] $(,)?) => {
+        $crate::menu::assert_prompts($input, &[$($e),*], $f)
+    };
+}
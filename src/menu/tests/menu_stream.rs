@@ -1,6 +1,16 @@
-use crate::menu::MenuStream;
+use crate::menu::{MenuStream, Spinner, StripAnsi};
+use crate::prelude::{Format, Selected, Written};
+use std::cell::RefCell;
 use std::error::Error;
-use std::io::{BufRead, Write};
+use std::io::{BufRead, Read, Write};
+use std::time::Duration;
+
+#[test]
+fn stream_is_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<MenuStream<'_, std::io::Cursor<Vec<u8>>, Vec<u8>>>();
+    assert_send::<MenuStream<'_, &[u8], Vec<u8>>>();
+}
 
 #[test]
 fn basic() -> Result<(), Box<dyn Error>> {
@@ -26,3 +36,210 @@ fn borrow_params() -> Result<(), Box<dyn Error>> {
     let output = String::from_utf8(output)?;
     Ok(assert_eq!(output, "hey\n"))
 }
+
+#[test]
+fn strip_ansi() -> Result<(), Box<dyn Error>> {
+    let input = "\n".as_bytes();
+    let mut stream = MenuStream::new(input, StripAnsi::new(Vec::<u8>::new()));
+    write!(stream, "\x1b[1;3")?;
+    write!(stream, "2mhello\x1b[0m \x1b(Bworld")?;
+    let (_, output) = stream.retrieve();
+    let output = String::from_utf8(output.into_inner())?;
+    Ok(assert_eq!(output, "hello world"))
+}
+
+#[test]
+fn drain_pending() -> Result<(), Box<dyn Error>> {
+    let mut input = "1\n\n2\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output).drain_within(Duration::MAX);
+    let first: u8 = Written::from("first").prompt(&mut stream)?;
+    let second: u8 = Written::from("second")
+        .default_value("5")
+        .prompt(&mut stream)?;
+    Ok(assert_eq!((first, second), (1, 2)))
+}
+
+#[test]
+fn keep_empty_lines_without_drain() -> Result<(), Box<dyn Error>> {
+    let mut input = "1\n\n2\n".as_bytes();
+    let mut output = Vec::<u8>::new();
+    let mut stream = MenuStream::with(&mut input, &mut output);
+    let first: u8 = Written::from("first").prompt(&mut stream)?;
+    let second: u8 = Written::from("second")
+        .default_value("5")
+        .prompt(&mut stream)?;
+    Ok(assert_eq!((first, second), (1, 5)))
+}
+
+#[test]
+fn spinner() -> Result<(), Box<dyn Error>> {
+    let input = "".as_bytes();
+    let mut stream = MenuStream::new(input, Vec::<u8>::new());
+    {
+        let mut spinner = Spinner::inner_new(&mut stream, "wait", true)?;
+        spinner.tick()?;
+    }
+    let (_, output) = stream.retrieve();
+    let output = String::from_utf8(output)?;
+    Ok(assert_eq!(output, "\r| wait\r      \r"))
+}
+
+#[test]
+fn inactive_spinner() -> Result<(), Box<dyn Error>> {
+    let input = "".as_bytes();
+    let mut stream = MenuStream::new(input, Vec::<u8>::new());
+    {
+        let mut spinner = Spinner::inner_new(&mut stream, "wait", false)?;
+        spinner.tick()?;
+    }
+    Spinner::with_format(&mut stream, "wait", &Format::quiet(true))?.tick()?;
+    let (_, output) = stream.retrieve();
+    Ok(assert!(output.is_empty()))
+}
+
+/// Writer counting the calls to `flush`.
+#[derive(Default)]
+struct FlushCounter {
+    inner: Vec<u8>,
+    flushes: usize,
+}
+
+impl Write for FlushCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.flushes += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn flush_once_per_prompt() -> Result<(), Box<dyn Error>> {
+    let input = "Ahmad\nabc\n19\n2\n".as_bytes();
+    let mut stream = MenuStream::new(input, FlushCounter::default());
+
+    let _: String = Written::from("name").prompt(&mut stream)?;
+    let _: u8 = Written::from("age").prompt(&mut stream)?;
+    let _: u8 = Selected::new("amount", [("one", 1), ("two", 2)]).select(&mut stream)?;
+
+    let (_, output) = stream.retrieve();
+    Ok(assert_eq!(output.flushes, 4))
+}
+
+/// In-memory bidirectional handle, reading the given input and collecting the output,
+/// like a socket through a shared reference.
+struct Duplex {
+    input: RefCell<&'static [u8]>,
+    output: RefCell<Vec<u8>>,
+}
+
+impl Read for &Duplex {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.input.borrow_mut().read(buf)
+    }
+}
+
+impl Write for &Duplex {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.output.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn shared() -> Result<(), Box<dyn Error>> {
+    let conn = Duplex {
+        input: RefCell::new(b"Ahmad\n"),
+        output: RefCell::new(Vec::new()),
+    };
+    let name: String = {
+        let mut stream = MenuStream::shared(&conn);
+        Written::from("name").prompt(&mut stream)?
+    };
+    assert_eq!(name, "Ahmad");
+    Ok(assert_eq!(conn.output.into_inner(), b"--> name\n>> "))
+}
+
+#[test]
+fn mutable_into_owned() {
+    use crate::menu::Mutable;
+
+    let mut buf = vec![1, 2];
+    let borrowed = Mutable::Borrowed(&mut buf);
+    assert!(!borrowed.is_owned());
+    let owned: Mutable<'static, _> = borrowed.into_owned();
+    assert!(owned.is_owned());
+    assert_eq!(owned.retrieve(), [1, 2]);
+    assert!(buf.is_empty());
+
+    let mut stream = MenuStream::default();
+    let owned: Mutable<'static, _> = Mutable::Borrowed(&mut stream).into_owned();
+    assert!(owned.is_owned());
+}
+
+#[test]
+fn on_read() -> Result<(), Box<dyn Error>> {
+    let input = "  $NAME \n".as_bytes();
+    let mut stream =
+        MenuStream::new(input, Vec::<u8>::new()).on_read(|s| s.replace("$NAME", "Ahmad"));
+    let name: String = Written::from("name").prompt(&mut stream)?;
+    Ok(assert_eq!(name, "Ahmad"))
+}
+
+#[test]
+fn ring_writer() -> Result<(), Box<dyn Error>> {
+    use crate::menu::RingWriter;
+
+    let input = "1\n".as_bytes();
+    let mut stream = MenuStream::new(input, RingWriter::new(Vec::new(), 2));
+    let _: u8 = Selected::new("amount", [("one", 1), ("two", 2)]).select(&mut stream)?;
+
+    let (_, recent) = stream.retrieve();
+    assert!(recent.lines().eq(["[1] - one", "[2] - two"]));
+    assert_eq!(recent.partial(), ">> ");
+    Ok(assert_eq!(
+        recent.into_inner(),
+        b"--> amount\n[1] - one\n[2] - two\n>> "
+    ))
+}
+
+#[test]
+fn assert_menu() -> Result<(), Box<dyn Error>> {
+    let age: u8 = crate::assert_menu!(
+        "Ahmad\n19\n",
+        |mut stream| {
+            let _: String = Written::from("name").prompt(&mut stream)?;
+            Written::from("age").prompt(&mut stream)
+        },
+        expects: ["--> name", ">> ", "--> age"],
+    )?;
+    Ok(assert_eq!(age, 19))
+}
+
+#[test]
+#[should_panic(expected = "`--> name` not found in order")]
+fn assert_menu_order() {
+    let _ = crate::assert_menu!(
+        "Ahmad\n19\n",
+        |mut stream| {
+            let _: String = Written::from("name").prompt(&mut stream)?;
+            Written::from("age").prompt::<_, _, u8>(&mut stream)
+        },
+        expects: ["--> age", "--> name"],
+    );
+}
+
+#[test]
+fn wrap_reader_with_capacity() -> Result<(), Box<dyn Error>> {
+    let input = "a long token\n".as_bytes();
+    let mut stream = MenuStream::wrap_reader_with_capacity(4, input, Vec::<u8>::new());
+    let token: String = Written::from("token").prompt(&mut stream)?;
+    assert_eq!(stream.reader_mut().capacity(), 4);
+    Ok(assert_eq!(token, "a long token"))
+}
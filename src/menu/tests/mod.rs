@@ -1,2 +1,3 @@
 mod menu_stream;
+mod raw_menu;
 mod values;
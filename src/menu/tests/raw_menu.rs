@@ -0,0 +1,325 @@
+use std::error::Error;
+
+use crate::prelude::*;
+
+type Res = Result<(), Box<dyn Error>>;
+
+macro_rules! test_menu {
+    ($name:ident, $input:expr, $fields:expr, $($st:stmt),* $(,)?) => {{
+        let mut input = $input.as_bytes();
+        let mut output = Vec::<u8>::new();
+        {
+            let fields: Fields<&[u8], Vec<u8>> = $fields;
+            let mut $name = RawMenu::owned(MenuStream::with(&mut input, &mut output), fields);
+            {$($st)*}
+        }
+        String::from_utf8(output)
+    }};
+}
+
+#[test]
+fn run_iter() -> Res {
+    let output = test_menu! {
+        menu,
+        "1\n2\n1\n3\n",
+        &[
+            ("Play", Kind::Map(&|_| Ok(()))),
+            ("Settings", Kind::Parent(&[("Back", Kind::Back(1))])),
+            ("Quit", Kind::Quit),
+        ],
+        let selected: MenuResult<Vec<&str>> = menu.run_iter().collect(),
+        assert_eq!(selected, Ok(vec!["Play", "Settings"])),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - Play\n[2] - Settings\n[3] - Quit\n>> \
+[1] - Play\n[2] - Settings\n[3] - Quit\n>> \
+--> Settings\n[1] - Back\n>> \
+[1] - Play\n[2] - Settings\n[3] - Quit\n>> "
+    ))
+}
+
+#[test]
+fn dynamic_title() -> Res {
+    use std::{cell::Cell, rc::Rc};
+
+    let remaining = Rc::new(Cell::new(2));
+    let deleted = remaining.clone();
+    let title = || format!("Delete item ({} remaining)", remaining.get());
+
+    let output = test_menu! {
+        menu,
+        "1\n2\n",
+        &[
+            ("Delete", Kind::Map(&move |_| Ok(deleted.set(deleted.get() - 1)))),
+            ("Quit", Kind::Quit),
+        ],
+        menu = menu.title_with(&title),
+        menu.run()?,
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> Delete item (2 remaining)\n[1] - Delete\n[2] - Quit\n>> \
+--> Delete item (1 remaining)\n[1] - Delete\n[2] - Quit\n>> "
+    ))
+}
+
+#[test]
+fn run_value() -> Res {
+    let output = test_menu! {
+        menu,
+        "2\n1\n",
+        &[
+            ("Easy", Kind::Map(&|s| Ok(s.set_output(1u8)))),
+            ("Custom", Kind::Parent(&[
+                ("Hard", Kind::Map(&|s| Ok(s.set_output(3u8)))),
+            ])),
+        ],
+        let level: Option<u8> = menu.run_value()?,
+        assert_eq!(level, Some(3)),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - Easy\n[2] - Custom\n>> --> Custom\n[1] - Hard\n>> "
+    ))
+}
+
+#[test]
+fn run_value_incorrect_type() -> Res {
+    test_menu! {
+        menu,
+        "1\n",
+        &[("Easy", Kind::Map(&|s| Ok(s.set_output(1u8))))],
+        let level: MenuResult<Option<String>> = menu.run_value(),
+        assert!(matches!(level, Err(MenuError::Other(_)))),
+    }?;
+    Ok(())
+}
+
+#[test]
+fn run_value_discards_previous_output() -> Res {
+    test_menu! {
+        menu,
+        "1\n2\n",
+        &[("Easy", Kind::Map(&|s| Ok(s.set_output(1u8)))), ("Quit", Kind::Quit)],
+        menu.run()?,
+        let level: Option<u8> = menu.run_value()?,
+        assert_eq!(level, None),
+    }?;
+    Ok(())
+}
+
+#[test]
+fn footer() -> Res {
+    let output = test_menu! {
+        menu,
+        "2\n1\n1\n",
+        &[
+            ("Play", Kind::Map(&|_| Ok(()))),
+            ("Settings", Kind::Parent(&[("Back", Kind::Back(1))])),
+        ],
+        menu = menu.footer("Type a number").run_once(true),
+        assert_eq!(
+            menu.to_string(),
+            "1 - Play\n2 - Settings\n--> Type a number\n"
+        ),
+        menu.run()?,
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - Play\n[2] - Settings\n--> Type a number\n>> \
+        --> Settings\n[1] - Back\n>> \
+        [1] - Play\n[2] - Settings\n--> Type a number\n>> "
+    ))
+}
+
+#[test]
+fn on_invalid() -> Res {
+    use std::io::Write;
+
+    let output = test_menu! {
+        menu,
+        "plya\n1\n",
+        &[("Play", Kind::Map(&|_| Ok(()))), ("Quit", Kind::Quit)],
+        menu = menu
+            .on_invalid(|input, s| Ok(writeln!(s, "unknown action: {}", input)?))
+            .run_once(true),
+        menu.run()?,
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - Play\n[2] - Quit\n>> unknown action: plya\n>> "
+    ))
+}
+
+#[test]
+fn context() -> Res {
+    let output = test_menu! {
+        menu,
+        "1\n1\n2\n",
+        &[
+            ("Add", Kind::Map(&|s| {
+                if let Some(count) = s.context_mut::<u8>() {
+                    *count += 1;
+                }
+                Ok(())
+            })),
+            ("Quit", Kind::Quit),
+        ],
+        menu.get_mut_object().set_context(1u8),
+        menu = menu.format(Format::quiet(true)),
+        menu.run()?,
+        assert_eq!(menu.get_object().context::<String>(), None),
+        assert_eq!(menu.get_mut_object().take_context::<u8>(), Some(3)),
+        assert_eq!(menu.get_object().context::<u8>(), None),
+    }?;
+
+    Ok(assert!(output.is_empty()))
+}
+
+#[test]
+fn empty_menu() -> Res {
+    let output = test_menu! {
+        menu,
+        "1\n1\n",
+        &[("Empty", Kind::Parent(&[]))],
+        assert_eq!(menu.run(), Err(MenuError::EmptyMenu)),
+    }?;
+
+    Ok(assert_eq!(output, "[1] - Empty\n>> "))
+}
+
+#[test]
+fn max_height() -> Res {
+    let output = test_menu! {
+        menu,
+        ">\n>\n<\n4\n",
+        &[
+            ("a", Kind::Quit),
+            ("b", Kind::Quit),
+            ("c", Kind::Quit),
+            ("d", Kind::Quit),
+            ("e", Kind::Quit),
+        ],
+        menu = menu.max_height(2),
+        menu.run()?,
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - a\n[2] - b\n--> 1-2 of 5 (`>` next, `<` previous)\n>> \
+         [3] - c\n[4] - d\n--> 3-4 of 5 (`>` next, `<` previous)\n>> \
+         [4] - d\n[5] - e\n--> 4-5 of 5 (`>` next, `<` previous)\n>> \
+         [2] - b\n[3] - c\n--> 2-3 of 5 (`>` next, `<` previous)\n>> "
+    ))
+}
+
+#[test]
+fn run_scripted() -> Res {
+    let output = test_menu! {
+        menu,
+        "2\n",
+        &[
+            ("Play", Kind::Map(&|s| {
+                let name: String = Written::from("name").prompt(s)?;
+                assert_eq!(name, "Ahmad");
+                // The raw reads also consume the script.
+                let mut raw = Vec::new();
+                std::io::BufRead::read_until(s, b'\n', &mut raw)?;
+                Ok(assert_eq!(raw, b"raw\n"))
+            })),
+            ("Quit", Kind::Quit),
+        ],
+        menu.run_scripted(&["1", "Ahmad", "raw"])?,
+        // Once the script is exhausted, the stream reads from the reader again.
+        menu.run()?,
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - Play\n[2] - Quit\n>> --> name\n>> [1] - Play\n[2] - Quit\n>> \
+        [1] - Play\n[2] - Quit\n>> "
+    ))
+}
+
+#[test]
+fn run_scripted_short() -> Res {
+    let output = test_menu! {
+        menu,
+        "",
+        &[
+            ("Play", Kind::Map(&|s| Written::from("name").prompt(s).map(|_: String| ()))),
+            ("Quit", Kind::Quit),
+        ],
+        // The mapped function lacks inputs.
+        assert_eq!(menu.run_scripted(&["1"]), Err(MenuError::Eof)),
+        // The menu lacks inputs to select a field.
+        assert_eq!(menu.run_scripted(&[]), Ok(())),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - Play\n[2] - Quit\n>> --> name\n>> [1] - Play\n[2] - Quit\n>> "
+    ))
+}
+
+/// Reader waiting for the given delay before each read, like a slow user.
+struct SlowReader {
+    inner: &'static [u8],
+    delay: std::time::Duration,
+}
+
+impl std::io::Read for SlowReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        std::thread::sleep(self.delay);
+        self.inner.read(buf)
+    }
+}
+
+#[test]
+fn session_timeout() -> Res {
+    use std::io::BufReader;
+    use std::time::Duration;
+
+    let slow = |delay| {
+        BufReader::new(SlowReader {
+            inner: b"1\n",
+            delay,
+        })
+    };
+    let fields: Fields<BufReader<SlowReader>, Vec<u8>> = &[("Quit", Kind::Quit)];
+
+    let stream = MenuStream::new(slow(Duration::from_millis(20)), Vec::new());
+    let mut menu = RawMenu::owned(stream, fields).session_timeout(Duration::from_millis(1));
+    assert_eq!(menu.run(), Err(MenuError::Timeout));
+
+    let stream = MenuStream::new(slow(Duration::ZERO), Vec::new());
+    let mut menu = RawMenu::owned(stream, fields).session_timeout(Duration::from_secs(60));
+    Ok(assert_eq!(menu.run(), Ok(())))
+}
+
+#[test]
+fn prompt() -> Res {
+    let output = test_menu! {
+        menu,
+        "1\n1\n2\n",
+        &[
+            ("Settings", Kind::Parent(&[("Back", Kind::Back(1))])),
+            ("Quit", Kind::Quit),
+        ],
+        menu = menu.prompt("Pick an action: "),
+        menu.run()?,
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "[1] - Settings\n[2] - Quit\nPick an action: \
+--> Settings\n[1] - Back\n>> \
+[1] - Settings\n[2] - Quit\nPick an action: "
+    ))
+}
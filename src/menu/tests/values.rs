@@ -1,4 +1,5 @@
 use std::error::Error;
+use std::time::Duration;
 
 #[cfg(feature = "date")]
 use crate::chrono::prelude::*;
@@ -165,6 +166,25 @@ fn optional_written() -> Res {
     Ok(assert_eq!(output, "--> age (optional)\n>> "))
 }
 
+#[test]
+fn mark_optional() -> Res {
+    let output = test_menu! {
+        menu,
+        "\n\n",
+        menu.fmt = Format::mark_optional(false),
+        let age: Option<u8> = menu.optional_written(&Written::from("age"))?,
+        assert_eq!(age, None),
+        let amount: Option<u8> =
+            menu.optional_selected(Selected::new("amount", [("one", 1), ("two", 2)]))?,
+        assert_eq!(amount, None),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> age\n>> --> amount\n[1] - one\n[2] - two\n>> "
+    ))
+}
+
 #[test]
 fn optional_select() -> Res {
     let sel = Selected::new("amount", [("one", 1), ("two", 2), ("three", 3)]);
@@ -194,13 +214,21 @@ fn optional_select() -> Res {
 }
 
 #[test]
-#[should_panic]
-fn select_no_field() {
-    let _output = test_menu! {
+fn select_no_field() -> Res {
+    let output = test_menu! {
         menu,
-        "hello",
-        let _msg: MenuResult = menu.selected(Selected::new("hey", [])),
-    };
+        "hello\n",
+        assert_eq!(menu.selected::<(), 0>(Selected::new("hey", [])), Err(MenuError::EmptyMenu)),
+        let filtered = Selected::new("hey", [("a", 1), ("b", 2)]).filter(|v| *v > 2),
+        assert_eq!(menu.selected(filtered.clone()), Err(MenuError::EmptyMenu)),
+        assert_eq!(menu.optional_selected(filtered.clone()), Err(MenuError::EmptyMenu)),
+        assert_eq!(
+            filtered.select_many(menu.get_mut_object(), ","),
+            Err(MenuError::EmptyMenu)
+        ),
+    }?;
+
+    Ok(assert!(output.is_empty()))
 }
 
 #[derive(Debug, PartialEq)]
@@ -261,6 +289,22 @@ fn selectable() -> Res {
     ))
 }
 
+#[test]
+fn from_selectable() -> Res {
+    let output = test_menu! {
+        menu,
+        "\n",
+        let sel = Selected::<Type2, 3>::from_selectable("select the type").default_of_type(),
+        let name = menu.selected(sel)?,
+        assert_eq!(name, Type2::MIT),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select the type\n[1] - MIT (default)\n[2] - GPL\n[3] - BSD\n>> "
+    ))
+}
+
 #[test]
 fn select_default() -> Res {
     let output = test_menu! {
@@ -279,3 +323,1597 @@ fn select_default() -> Res {
 >> "
     ))
 }
+
+#[test]
+fn select_by_label() -> Res {
+    let output = test_menu! {
+        menu,
+        "gpl\n",
+        let name: Type2 = menu.selected(Selected::from("select the type"))?,
+        assert_eq!(name, Type2::GPL),
+    }?;
+
+    assert_eq!(
+        output,
+        "--> select the type
+[1] - MIT
+[2] - GPL
+[3] - BSD
+>> "
+    );
+
+    let sel = Selected::new("select the amount", [("1", 3), ("2", 1), ("3", 2)]);
+    let output = test_menu! {
+        menu,
+        "2\n",
+        let amount: u8 = menu.selected(sel.clone().format(Format::match_mode(MatchMode::LabelFirst)))?,
+        assert_eq!(amount, 1),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select the amount
+[1] - 1
+[2] - 2
+[3] - 3
+>> "
+    ))
+}
+
+#[test]
+fn parse_with() -> Res {
+    let output = test_menu! {
+        menu,
+        "#zz\n#ff8000\n",
+        let color = Written::from("color")
+            .parse_with(menu.get_mut_object(), |s| u32::from_str_radix(s.trim_start_matches('#'), 16))?,
+        assert_eq!(color, 0xff8000),
+    }?;
+
+    Ok(assert_eq!(output, "--> color\n>> >> "))
+}
+
+#[test]
+fn select_eof() -> Res {
+    let sel = Selected::new("amount", [("one", 1), ("two", 2), ("three", 3)]);
+
+    let output = test_menu! {
+        menu,
+        "",
+        let amount: u8 = menu.selected(sel.clone().default(1))?,
+        assert_eq!(amount, 2),
+    }?;
+
+    assert_eq!(
+        output,
+        "--> amount
+[1] - one
+[2] - two (default)
+[3] - three
+>> "
+    );
+
+    let _output = test_menu! {
+        menu,
+        "zmelkfj\n",
+        let amount: MenuResult<u8> = menu.selected(sel),
+        assert_eq!(amount, Err(MenuError::Eof)),
+    }?;
+
+    Ok(())
+}
+
+#[test]
+fn invalid_msg() -> Res {
+    let output = test_menu! {
+        menu,
+        "-5\nlol\n5\n",
+        menu.fmt = Format::invalid_msg("incorrect age"),
+        let age: u8 = menu.written(&Written::from("age"))?,
+        assert_eq!(age, 5),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> age\n>> incorrect age\n>> incorrect age\n>> "
+    ))
+}
+
+#[test]
+fn show_mark_default() -> Res {
+    let output = test_menu! {
+        menu,
+        "\n\n",
+        menu.fmt = Format::mark_default(false),
+        let age: u8 = menu.written(&Written::from("age").default_value("18"))?,
+        assert_eq!(age, 18),
+        let amount: u8 = menu.selected(Selected::new("amount", [("one", 1), ("two", 2)]).default(1))?,
+        assert_eq!(amount, 2),
+    }?;
+
+    assert_eq!(
+        output,
+        "--> age (default: 18)\n>> --> amount (optional)\n[1] - one\n[2] - two\n>> "
+    );
+
+    let output = test_menu! {
+        menu,
+        "\n\n",
+        menu.fmt = Format::show_default(false),
+        let age: u8 = menu.written(&Written::from("age").default_value("18"))?,
+        assert_eq!(age, 18),
+        let amount: u8 = menu.selected(Selected::new("amount", [("one", 1), ("two", 2)]).default(1))?,
+        assert_eq!(amount, 2),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> age\n>> --> amount\n[1] - one\n[2] - two (default)\n>> "
+    ))
+}
+
+#[test]
+fn many_written() -> Res {
+    let output = test_menu! {
+        menu,
+        "a,, b ,c\na,, b ,c\n",
+        let values: Vec<String> = menu.many_written(&Written::from("values"), ",")?,
+        assert_eq!(values, ["a", "b", "c"]),
+        let values: Vec<String> = menu.many_written_keep_empty(&Written::from("values"), ",")?,
+        assert_eq!(values, ["a", "", " b ", "c"]),
+    }?;
+
+    Ok(assert_eq!(output, "--> values\n>> --> values\n>> "))
+}
+
+#[test]
+fn selected_range() -> Res {
+    let output = test_menu! {
+        menu,
+        "13\n0\n12\n",
+        let month: u8 = menu.selected_range(SelectedRange::new("month", 1..=12))?,
+        assert_eq!(month, 12),
+    }?;
+
+    Ok(assert_eq!(output, "--> month (1–12)\n>> >> >> "))
+}
+
+#[test]
+fn echo_newline() -> Res {
+    let output = test_menu! {
+        menu,
+        "Ahmad\n19\n",
+        menu.fmt = Format::echo_newline(true),
+        let name: String = menu.written(&Written::from("name"))?,
+        assert_eq!(name, "Ahmad"),
+        let age: u8 = menu.written(&Written::from("age"))?,
+        assert_eq!(age, 19),
+    }?;
+
+    Ok(assert_eq!(output, "--> name\n>> \n--> age\n>> \n"))
+}
+
+#[test]
+fn select_attempts() -> Res {
+    let output = test_menu! {
+        menu,
+        "\n0\n4\n1\n",
+        let amount: MenuResult<u8> = menu.selected(Selected::new("amount", [("one", 1), ("two", 2)]).attempts(3)),
+        assert_eq!(amount, Err(MenuError::NoSelection)),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> amount\n[1] - one\n[2] - two\n>> >> >> "
+    ))
+}
+
+#[test]
+fn custom_render() -> Res {
+    use std::fmt;
+
+    fn render(parts: &FieldParts<'_>, w: &mut dyn fmt::Write) -> fmt::Result {
+        write!(w, "{}?", parts.msg.to_uppercase())?;
+        if let Some(d) = parts.default {
+            write!(w, " [{}]", d)?;
+        }
+        for label in parts.labels {
+            write!(w, "\n* {}", label)?;
+        }
+        Ok(())
+    }
+
+    let output = test_menu! {
+        menu,
+        "\n2\n",
+        menu.fmt = Format::custom_render(Some(render)),
+        let age: u8 = menu.written(&Written::from("age").default_value("18"))?,
+        assert_eq!(age, 18),
+        let amount: u8 = menu.selected(Selected::new("amount", [("one", 1), ("two", 2)]))?,
+        assert_eq!(amount, 2),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "AGE? [18]\n>> AMOUNT?\n* one\n* two\n>> "
+    ))
+}
+
+#[test]
+fn select_or() -> Res {
+    let output = test_menu! {
+        menu,
+        "zmrlkgjzmklj\n",
+        let name = menu.selected_or(Selected::from("select the type"), Type2::BSD),
+        assert_eq!(name, Type2::BSD),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select the type (optional)
+[1] - MIT
+[2] - GPL
+[3] - BSD
+>> "
+    ))
+}
+
+#[test]
+fn with_unit() -> Res {
+    let units = [("B", 1), ("KB", 1_000), ("MB", 1_000_000)];
+    let output = test_menu! {
+        menu,
+        "10TB\n10 KB\n",
+        let size = Written::from("size").with_unit(menu.get_mut_object(), &units)?,
+        assert_eq!(size, 10_000),
+    }?;
+
+    Ok(assert_eq!(output, "--> size\n>> >> "))
+}
+
+#[test]
+fn many_written_lines() -> Res {
+    let output = test_menu! {
+        menu,
+        "1\nfoo\n2\ndone\n3\n\n",
+        menu = menu.record(true),
+        let field = Written::from("numbers"),
+        let numbers: Vec<u8> = menu.many_written_lines(&field, "done")?,
+        assert_eq!(numbers, [1, 2]),
+        let numbers: Vec<u8> = menu.many_written_lines(&field.format(Format::quiet(true)), "done")?,
+        assert_eq!(numbers, [3]),
+        assert_eq!(
+            menu.records(),
+            [("numbers".to_owned(), "1, 2".to_owned()), ("numbers".to_owned(), "3".to_owned())]
+        ),
+    }?;
+
+    Ok(assert_eq!(output, "--> numbers\n>> >> >> >> "))
+}
+
+#[test]
+fn many_written_lines_normalized() -> Res {
+    let output = test_menu! {
+        menu,
+        "A\nDONE\nfr\nde\n:undo\ndone\n",
+        let field = Written::from("names").normalize(Normalization::Lower),
+        let names: Vec<String> = menu.many_written_lines(&field, "DONE")?,
+        assert_eq!(names, ["a"]),
+        let field = Written::from("codes").normalize(Normalization::Upper).undo_token(":undo"),
+        let codes: Vec<String> = menu.many_written_lines(&field, "done")?,
+        assert_eq!(codes, ["FR"]),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> names\n>> >> --> codes\n>> >> >> >> "
+    ))
+}
+
+#[test]
+fn many_written_lines_undo() -> Res {
+    let output = test_menu! {
+        menu,
+        ":undo\n1\n20\n:undo\n2\ndone\n",
+        menu = menu.record(true),
+        let numbers: Vec<u8> = menu.many_written_lines(&Written::from("numbers").undo_token(":undo"), "done")?,
+        assert_eq!(numbers, [1, 2]),
+        assert_eq!(menu.records(), [("numbers".to_owned(), "1, 2".to_owned())]),
+    }?;
+
+    Ok(assert_eq!(output, "--> numbers\n>> >> >> >> >> >> "))
+}
+
+#[test]
+fn written_array() -> Res {
+    let output = test_menu! {
+        menu,
+        "1, 2\n1, a, 3\n1, 2, 3\n",
+        let field = Written::from("coords").format(Format::invalid_msg("Invalid input.")),
+        let coords: [i32; 3] = menu.written_array(&field, ",")?,
+        assert_eq!(coords, [1, 2, 3]),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> coords\n>> Invalid input.\n - expected exactly 3 values\n\
+         >> Invalid input.\n - incorrect value\n>> "
+    ))
+}
+
+#[test]
+fn written_map() -> Res {
+    let users = [("ahmad", 1), ("bob", 2)];
+    let output = test_menu! {
+        menu,
+        "alice\nbob\n",
+        let id: u8 = menu.written_map(&Written::from("user"), |name: String| {
+            users.iter().find(|(n, _)| *n == name).map(|(_, id)| *id).ok_or(())
+        })?,
+        assert_eq!(id, 2),
+    }?;
+
+    Ok(assert_eq!(output, "--> user\n>> >> "))
+}
+
+#[test]
+fn duration() -> Res {
+    let output = test_menu! {
+        menu,
+        "1h30\n1h 30m\n2d\n250ms 1s\n\n",
+        let field = Written::from("timeout").format(Format::invalid_msg("Invalid input.")),
+        assert_eq!(field.duration(menu.get_mut_object())?, Duration::from_secs(5400)),
+        assert_eq!(field.duration(menu.get_mut_object())?, Duration::from_secs(2 * 86400)),
+        assert_eq!(field.duration(menu.get_mut_object())?, Duration::from_millis(1250)),
+        let field = field.default_value("90s").format(Format::quiet(true)),
+        assert_eq!(field.duration(menu.get_mut_object())?, Duration::from_secs(90)),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> timeout\n>> Invalid input.\n - expected a duration, for example `1h30m`\n>> \
+         --> timeout\n>> --> timeout\n>> "
+    ))
+}
+
+#[test]
+fn inline_block() -> Res {
+    let output = test_menu! {
+        menu,
+        "Ahmad\n19\n",
+        menu.fmt = Format {
+            line_brk: false,
+            suffix: ": ",
+            ..Default::default()
+        },
+        let name: String = menu.written(&Written::from("name").block())?,
+        assert_eq!(name, "Ahmad"),
+        let age: u8 = menu.written(&Written::from("age").inline())?,
+        assert_eq!(age, 19),
+    }?;
+
+    Ok(assert_eq!(output, "--> name\n: --> age: "))
+}
+
+#[test]
+fn prefill_fallback() -> Res {
+    let output = test_menu! {
+        menu,
+        "\n",
+        let name: String = menu.written(&Written::from("name").default_value("Ahmad").prefill(true))?,
+        assert_eq!(name, "Ahmad"),
+    }?;
+
+    Ok(assert_eq!(output, "--> name (default: Ahmad)\n>> "))
+}
+
+#[test]
+fn quiet() -> Res {
+    let output = test_menu! {
+        menu,
+        "Ahmad\nabc\n19\n2\n",
+        menu.fmt = Format {
+            quiet: true,
+            invalid_msg: "invalid",
+            ..Default::default()
+        },
+        let name: String = menu.written(&Written::from("name"))?,
+        assert_eq!(name, "Ahmad"),
+        let age: u8 = menu.written(&Written::from("age"))?,
+        assert_eq!(age, 19),
+        let amount: u8 = menu.selected(Selected::new("amount", [("one", 1), ("two", 2)]))?,
+        assert_eq!(amount, 2),
+    }?;
+
+    Ok(assert_eq!(output, ""))
+}
+
+#[test]
+fn select_details() -> Res {
+    let output = test_menu! {
+        menu,
+        "?2\n?3\n2\n",
+        let amount: u8 = menu.selected(
+            Selected::new("amount", [("one", 1), ("two", 2)]).details(["just one", "a pair"])
+        )?,
+        assert_eq!(amount, 2),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> amount\n[1] - one\n[2] - two\n>> --> a pair\n>> >> "
+    ))
+}
+
+#[test]
+fn transform() -> Res {
+    let output = test_menu! {
+        menu,
+        "fr\n1234-5678\n",
+        let code: String = menu.written(&Written::from("code").transform(|s| s.to_uppercase()))?,
+        assert_eq!(code, "FR"),
+        let card: u64 = menu.written(&Written::from("card").transform(|s| s.replace('-', "")))?,
+        assert_eq!(card, 12345678),
+    }?;
+
+    Ok(assert_eq!(output, "--> code\n>> --> card\n>> "))
+}
+
+#[test]
+fn select_groups() -> Res {
+    let output = test_menu! {
+        menu,
+        "3\n",
+        let license = menu.selected(
+            Selected::new("license", [("MIT", 0), ("BSD", 1), ("GPL", 2)])
+                .group(0, "-- Permissive --")
+                .group(2, "-- Copyleft --")
+        )?,
+        assert_eq!(license, 2),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> license
+-- Permissive --
+[1] - MIT
+[2] - BSD
+-- Copyleft --
+[3] - GPL
+>> "
+    ))
+}
+
+#[test]
+fn select_labeled() -> Res {
+    let output = test_menu! {
+        menu,
+        "3\n",
+        let (label, ty) = Selected::<Type2, 3>::from("select the type").select_labeled(menu.get_mut_object())?,
+        assert_eq!(label, "BSD"),
+        assert_eq!(ty, Type2::BSD),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select the type
+[1] - MIT
+[2] - GPL
+[3] - BSD
+>> "
+    ))
+}
+
+#[test]
+fn select_cloned() -> Res {
+    let output = test_menu! {
+        menu,
+        "2\n1\n",
+        let field = Selected::new("amount", [("one", 1), ("two", 2)]).format(Format::quiet(true)),
+        assert_eq!(field.select_cloned(menu.get_mut_object())?, 2),
+        assert_eq!(field.select_cloned(menu.get_mut_object())?, 1),
+    }?;
+
+    Ok(assert!(output.is_empty()))
+}
+
+#[test]
+fn select_choice() -> Res {
+    let output = test_menu! {
+        menu,
+        "linter\n",
+        let choice = Selected::new("plugin", [("formatter", 'f'), ("linter", 'l')])
+            .format(Format::quiet(true))
+            .details(["Formats the code.", "Checks the code."])
+            .select_choice(menu.get_mut_object())?,
+        assert_eq!(
+            choice,
+            Choice {
+                label: "linter",
+                index: 1,
+                detail: Some("Checks the code."),
+                value: 'l',
+            }
+        ),
+    }?;
+
+    Ok(assert!(output.is_empty()))
+}
+
+#[test]
+fn from_arg() -> Res {
+    let output = test_menu! {
+        menu,
+        "19\n",
+        let name: String = menu.written(&Written::from("name").arg(Some("Ahmad".to_owned())))?,
+        assert_eq!(name, "Ahmad"),
+        let age: u8 = menu.written(&Written::from("age").arg(Some("abc".to_owned())))?,
+        assert_eq!(age, 19),
+    }?;
+
+    Ok(assert_eq!(output, "--> age\n>> "))
+}
+
+#[test]
+fn index_pad() -> Res {
+    let output = test_menu! {
+        menu,
+        "10\n",
+        menu.fmt = Format::index_pad(Some('0')),
+        let n: u8 = menu.selected(Selected::new("number", [
+            ("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5),
+            ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9), ("ten", 10),
+        ]))?,
+        assert_eq!(n, 10),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> number
+[01] - one
+[02] - two
+[03] - three
+[04] - four
+[05] - five
+[06] - six
+[07] - seven
+[08] - eight
+[09] - nine
+[10] - ten
+>> "
+    ))
+}
+
+#[test]
+fn confirm_summary() -> Res {
+    let output = test_menu! {
+        menu,
+        "Ahmad\n\n2\nn\nAhmad\n19\n1\nYes\n",
+        menu = menu.record(true),
+        let amount = Selected::new("amount", [("one", 1), ("two", 2)]),
+        let _: String = menu.written(&Written::from("name"))?,
+        let _: u8 = menu.written(&Written::from("age").default_value("18"))?,
+        let _: u8 = menu.selected(amount.clone())?,
+        assert!(!menu.confirm_summary()?),
+        let _: String = menu.written(&Written::from("name"))?,
+        let _: u8 = menu.written(&Written::from("age").default_value("18"))?,
+        let _: u8 = menu.selected(amount)?,
+        assert_eq!(
+            menu.records(),
+            [
+                ("name".to_owned(), "Ahmad".to_owned()),
+                ("age".to_owned(), "19".to_owned()),
+                ("amount".to_owned(), "one".to_owned()),
+            ]
+        ),
+        assert!(menu.confirm_summary()?),
+        assert!(menu.records().is_empty()),
+    }?;
+
+    Ok(assert!(output.contains(
+        "--> name: Ahmad\n--> age: 18\n--> amount: two\n--> Confirm? [y/N]\n>> "
+    )))
+}
+
+#[test]
+fn boolean() -> Res {
+    let output = test_menu! {
+        menu,
+        "YES\nmaybe\noff\n\n",
+        let a: bool = menu.written(&Written::from("a").boolean())?,
+        assert!(a),
+        let b: bool = menu.written(&Written::from("b").boolean())?,
+        assert!(!b),
+        let c: bool = menu.written(&Written::from("c").boolean().default_value("y"))?,
+        assert!(c),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> a [y/n]\n>> --> b [y/n]\n>> >> --> c [Y/n]\n>> "
+    ))
+}
+
+#[test]
+fn secret_env() -> Res {
+    let lookup: fn(&str) -> Option<String> = |var| match var {
+        "TOKEN" => Some("hunter2".to_owned()),
+        _ => None,
+    };
+    let output = test_menu! {
+        menu,
+        "s3cret\n",
+        menu = menu.record(true),
+        let a: String = menu.written(&Written::from("a").secret_env_with("TOKEN", lookup))?,
+        assert_eq!(a, "hunter2"),
+        let b: String = menu.written(
+            &Written::from("b").secret_env_with("UNSET", lookup).default_value("default"),
+        )?,
+        assert_eq!(b, "s3cret"),
+        let c: String = Written::from("c")
+            .arg(Some("given".to_owned()))
+            .secret_env_with("TOKEN", lookup)
+            .prompt(menu.get_mut_object())?,
+        assert_eq!(c, "given"),
+        assert_eq!(
+            menu.records(),
+            [
+                ("a".to_owned(), "*******".to_owned()),
+                ("b".to_owned(), "******".to_owned()),
+            ]
+        ),
+    }?;
+
+    Ok(assert_eq!(output, "--> b\n>> "))
+}
+
+#[test]
+fn secret_last_input() -> Res {
+    let mut stream = MenuStream::new("s3cret\n".as_bytes(), Vec::<u8>::new());
+    let secret: String = Written::from("secret")
+        .secret_env_with("UNSET", |_| None)
+        .prompt(&mut stream)?;
+    assert_eq!(secret, "s3cret");
+    Ok(assert_eq!(stream.last_input(), Some("******")))
+}
+
+#[test]
+fn parse_any() -> Res {
+    type Parser = fn(&str) -> Result<u32, String>;
+    let parsers: [Parser; 2] = [
+        |s| s.parse().map_err(|_| "not a decimal".to_owned()),
+        |s| {
+            let hex = s.strip_prefix("0x").ok_or("not a hexadecimal")?;
+            u32::from_str_radix(hex, 16).map_err(|_| "not a hexadecimal".to_owned())
+        },
+    ];
+
+    let output = test_menu! {
+        menu,
+        "ff\n0xff\n",
+        let addr = Written::from("addr")
+            .format(Format::invalid_msg("invalid address"))
+            .parse_any(menu.get_mut_object(), &parsers)?,
+        assert_eq!(addr, 255),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> addr\n>> invalid address\n - not a decimal\n - not a hexadecimal\n>> "
+    ))
+}
+
+#[test]
+fn defaults() -> Res {
+    let output = test_menu! {
+        menu,
+        "\n18\n\n",
+        let _: String = menu.written(&Written::from("name").default_value("Ahmad"))?,
+        let _: u8 = menu.written(&Written::from("age").default_value("20"))?,
+        let _: u8 = menu.selected(Selected::new("amount", [("one", 1), ("two", 2)]).default(1))?,
+        let _: String = menu.written(
+            &Written::from("city").arg(Some("Paris".to_owned())).default_value("Lyon"),
+        )?,
+        assert_eq!(menu.defaults(), ["name".to_owned(), "amount".to_owned()]),
+    }?;
+
+    Ok(assert!(output.ends_with(">> ")))
+}
+
+#[test]
+fn select_filter() -> Res {
+    let output = test_menu! {
+        menu,
+        "2\n",
+        let n: u8 = menu.selected(
+            Selected::new("amount", [("one", 1), ("two", 2), ("three", 3), ("four", 4)])
+                .filter(|n| n % 2 == 0)
+                .group(3, "-- many --")
+                .default(3),
+        )?,
+        assert_eq!(n, 4),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> amount\n[1] - two\n-- many --\n[2] - four (default)\n>> "
+    ))
+}
+
+#[test]
+fn select_many() -> Res {
+    let field = || Selected::new("features", [("log", 1), ("metrics", 2), ("tracing", 3)]);
+
+    let output = test_menu! {
+        menu,
+        "3, log, 5\n3,log,3\nall\n\n",
+        let many = field().format(Format::quiet(true)).select_many(menu.get_mut_object(), ",")?,
+        assert_eq!(many, [1, 3]),
+        let all = field()
+            .many_tokens("all", "none")
+            .select_many(menu.get_mut_object(), ",")?,
+        assert_eq!(all, [1, 2, 3]),
+        let none = field().format(Format::quiet(true)).select_many(menu.get_mut_object(), ",")?,
+        assert!(none.is_empty()),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> features\n[1] - log\n[2] - metrics\n[3] - tracing\n>> "
+    ))
+}
+
+#[test]
+fn radix() -> Res {
+    let output = test_menu! {
+        menu,
+        "0xff\nzz\n0b12\n7f\n\n-0x10\n",
+        let a: u8 = menu.written(&Written::from("a").radix(16))?,
+        assert_eq!(a, 255),
+        let b: u8 = menu.written(&Written::from("b").format(Format::quiet(true)).radix(16))?,
+        assert_eq!(b, 127),
+        let c: u8 = menu.written(&Written::from("c").radix(2).default_value("0b101"))?,
+        assert_eq!(c, 5),
+        let d: i8 = menu.written(&Written::from("d").radix(16))?,
+        assert_eq!(d, -16),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> a\n>> --> c (default: 0b101)\n>> --> d\n>> "
+    ))
+}
+
+#[test]
+fn suggest_default() -> Res {
+    let output = test_menu! {
+        menu,
+        "\nfoo\n19\n",
+        let age: u8 = menu.written(&Written::from("age").default_value("18").suggest_default())?,
+        assert_eq!(age, 19),
+        assert!(menu.defaults().is_empty()),
+    }?;
+
+    Ok(assert_eq!(output, "--> age (default: 18)\n>> >> >> "))
+}
+
+#[test]
+fn empty_input_precedence() -> Res {
+    let required = || Written::from("required").format(Format::quiet(true));
+    let with_default = || required().default_value("18");
+
+    test_menu! {
+        menu,
+        "\n19\n\n\n\n",
+        // Required without default: the field is prompted again.
+        let a: u8 = menu.written(&required())?,
+        assert_eq!(a, 19),
+        // Required with default: the default value is used.
+        let b: u8 = menu.written(&with_default())?,
+        assert_eq!(b, 18),
+        // Optional without default: no value.
+        let c: Option<u8> = menu.optional_written(&required())?,
+        assert_eq!(c, None),
+        // Optional with default: the default value is used.
+        let d: Option<u8> = menu.optional_written(&with_default())?,
+        assert_eq!(d, Some(18)),
+        // End of the input stream.
+        assert_eq!(menu.written::<u8>(&with_default()), Ok(18)),
+        assert_eq!(menu.optional_written::<u8>(&required()), Ok(None)),
+        assert_eq!(menu.written::<u8>(&required()), Err(MenuError::Eof)),
+    }?;
+
+    Ok(())
+}
+
+#[test]
+fn range() -> Res {
+    let output = test_menu! {
+        menu,
+        "0\n121\n40\n5 130\n5 6\n0 4\n3 4\n-2\n0.5\n1\n",
+        let age: u8 = menu.written(&Written::from("age").range(1..=120).example("19"))?,
+        assert_eq!(age, 40),
+        let ages: Vec<u8> = menu.many_written(&Written::from("ages").range(1..=120), " ")?,
+        assert_eq!(ages, [5, 6]),
+        let pair: [u8; 2] = Written::from("pair")
+            .range(1..=9)
+            .array_values(&mut menu.stream, " ")?,
+        assert_eq!(pair, [3, 4]),
+        let temp: i8 = menu.written(&Written::from("temp").range(-5..=-1))?,
+        assert_eq!(temp, -2),
+        // An input that cannot be compared with the bounds is incorrect.
+        let ratio: f32 = menu.written(&Written::from("ratio").range(0..=1))?,
+        assert_eq!(ratio, 1.0),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> age (1–120, example: 19)\n>> >> >> --> ages (1–120)\n>> >> \
+        --> pair (1–9)\n>> >> --> temp (-5–-1)\n>> --> ratio (0–1)\n>> >> "
+    ))
+}
+
+#[test]
+fn reask() -> Res {
+    let start_field = Written::from("start").format(Format::quiet(true));
+    let end_field = Written::from("end").format(Format::quiet(true));
+
+    test_menu! {
+        menu,
+        "5\n3\n8\n",
+        menu = menu.record(true),
+        let start: u8 = menu.written(&start_field)?,
+        let end: u8 = menu.written(&end_field)?,
+        assert!(start > end),
+        assert_eq!(menu.reask::<u8>(&start_field, &["end"])?, None),
+        assert_eq!(menu.reask::<u8>(&end_field, &["end"])?, Some(8)),
+        assert_eq!(
+            menu.records(),
+            [("start".to_owned(), "5".to_owned()), ("end".to_owned(), "8".to_owned())]
+        ),
+    }?;
+
+    Ok(())
+}
+
+#[test]
+fn mnemonics() -> Res {
+    let field = || Selected::new("action", [("Save", 1), ("Load", 2), ("Lock", 3)]);
+
+    let output = test_menu! {
+        menu,
+        "S\nl\n3\nl\n",
+        let save: u8 = menu.selected(field().mnemonics(true))?,
+        assert_eq!(save, 1),
+        // The "l" mnemonic is ambiguous, so it is not accepted.
+        let lock: u8 = menu.selected(field().format(Format::quiet(true)).mnemonics(false))?,
+        assert_eq!(lock, 3),
+        // Mnemonics are disabled by default.
+        assert_eq!(
+            menu.selected(field().format(Format::quiet(true))),
+            Err(MenuError::Eof)
+        ),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> action\n[1] - \x1b[4mS\x1b[0mave\n[2] - Load\n[3] - Lock\n>> "
+    ))
+}
+
+#[test]
+fn line_ending() -> Res {
+    let output = test_menu! {
+        menu,
+        "foo\n2\n",
+        menu.fmt = Format::line_ending(LineEnding::CrLf),
+        let name: String = menu.written(&Written::from("name"))?,
+        assert_eq!(name, "foo"),
+        let amount: u8 = menu.selected(Selected::new("amount", [("one", 1), ("two", 2)]))?,
+        assert_eq!(amount, 2),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> name\r\n>> --> amount\r\n[1] - one\r\n[2] - two\r\n>> "
+    ))
+}
+
+/// Reader failing with an IO error for the given amount of reads.
+struct Flaky<'a> {
+    fails: usize,
+    inner: &'a [u8],
+}
+
+impl std::io::Read for Flaky<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.fails > 0 {
+            self.fails -= 1;
+            return Err(std::io::ErrorKind::BrokenPipe.into());
+        }
+        self.inner.read(buf)
+    }
+}
+
+#[test]
+fn io_retries() -> Res {
+    let reader = std::io::BufReader::new(Flaky {
+        fails: 2,
+        inner: b"19\n2\n",
+    });
+    let mut stream = MenuStream::new(reader, Vec::new());
+    let age = || Written::from("age").format(Format::quiet(true));
+
+    assert!(matches!(
+        age().prompt::<_, _, u8>(&mut stream),
+        Err(MenuError::IOError(_))
+    ));
+    let age: u8 = age().io_retries(1).prompt(&mut stream)?;
+    assert_eq!(age, 19);
+    let amount: u8 = Selected::new("amount", [("one", 1), ("two", 2)])
+        .format(Format::quiet(true))
+        .io_retries(1)
+        .select(&mut stream)?;
+    assert_eq!(amount, 2);
+
+    Ok(())
+}
+
+#[test]
+fn hidden_index() -> Res {
+    let output = test_menu! {
+        menu,
+        "2\nTWO\n",
+        menu.fmt = Format::show_index(false),
+        let amount: u8 = menu.selected(Selected::new("amount", [("one", 1), ("two", 2)]))?,
+        assert_eq!(amount, 2),
+    }?;
+
+    Ok(assert_eq!(output, "--> amount\none\ntwo\n>> >> "))
+}
+
+#[test]
+fn banner() -> Res {
+    let output = test_menu! {
+        menu,
+        "Ahmad\n19\n",
+        menu = menu.banner("== Form =="),
+        let name: String = menu.written(&Written::from("name"))?,
+        assert_eq!(name, "Ahmad"),
+        let age: u8 = menu.written(&Written::from("age"))?,
+        assert_eq!(age, 19),
+    }?;
+
+    Ok(assert_eq!(output, "== Form ==\n--> name\n>> --> age\n>> "))
+}
+
+#[test]
+fn select_no_default() -> Res {
+    let base = Selected::new("amount", [("one", 1), ("two", 2)]).default(0);
+    let output = test_menu! {
+        menu,
+        "\n2\n",
+        let amount: u8 = menu.selected(base.clone().no_default())?,
+        assert_eq!(amount, 2),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> amount\n[1] - one\n[2] - two\n>> >> "
+    ))
+}
+
+#[test]
+fn confirm_quit() -> Res {
+    let output = test_menu! {
+        menu,
+        "maybe\ny\nQ\n\n",
+        let field = Written::from("next").quit("q"),
+        assert_eq!(field.confirm(menu.get_mut_object())?, Confirm::Yes),
+        assert_eq!(field.confirm(menu.get_mut_object())?, Confirm::Quit),
+        let field = field.default_value("q"),
+        assert_eq!(field.confirm(menu.get_mut_object())?.as_bool(), None),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> next [y/n/q]\n>> >> --> next [y/n/q]\n>> --> next [y/n/Q]\n>> "
+    ))
+}
+
+#[test]
+fn on_parse_error() -> Res {
+    let output = test_menu! {
+        menu,
+        "13\nmay\n\n5\n",
+        menu.fmt = Format::invalid_msg("Invalid input."),
+        let month: u8 = menu.written_until(
+            &Written::from("month").on_parse_error(|s| format!("'{}' is not a valid month", s)),
+            |m| (1..=12).contains(m),
+        )?,
+        assert_eq!(month, 5),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> month
+>> '13' is not a valid month
+>> 'may' is not a valid month
+>> Invalid input.
+>> "
+    ))
+}
+
+#[test]
+fn show_range() -> Res {
+    let output = test_menu! {
+        menu,
+        "2\n",
+        menu.fmt = Format::show_range(true),
+        let amount: u8 = menu.selected(Selected::new("amount", [("one", 1), ("two", 2)]))?,
+        assert_eq!(amount, 2),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> amount\n[1] - one\n[2] - two\n[1-2] >> "
+    ))
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn into_struct() -> Res {
+    #[derive(serde::Deserialize)]
+    struct Config {
+        name: String,
+        port: u16,
+        license: String,
+    }
+
+    test_menu! {
+        menu,
+        "server\n8080\n2\n",
+        menu = menu.record(true),
+        let _: String = menu.written(&Written::from("name"))?,
+        let _: u16 = menu.written(&Written::from("port"))?,
+        let _: Type2 = menu.selected(Selected::from("license"))?,
+        let config: Config = menu.into_struct()?,
+        assert_eq!(config.name, "server"),
+        assert_eq!(config.port, 8080),
+        assert_eq!(config.license, "GPL"),
+    }?;
+
+    Ok(())
+}
+
+#[test]
+fn select_default_by() -> Res {
+    let output = test_menu! {
+        menu,
+        "\n",
+        let port: (&str, u16) = menu.selected(
+            Selected::new("service", [("HTTP", ("http", 80)), ("HTTPS", ("https", 443))])
+                .default_by(|(name, _)| *name == "https"),
+        )?,
+        assert_eq!(port, ("https", 443)),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> service\n[1] - HTTP\n[2] - HTTPS (default)\n>> "
+    ))
+}
+
+#[test]
+fn lines_until() -> Res {
+    let output = test_menu! {
+        menu,
+        "-----BEGIN KEY-----\nabc\n\n  def\n-----END KEY-----\nnext\n",
+        let key = Written::from("key").lines_until(menu.get_mut_object(), |l| l.starts_with("-----END"))?,
+        assert_eq!(key, "-----BEGIN KEY-----\nabc\n\ndef\n-----END KEY-----"),
+    }?;
+
+    Ok(assert_eq!(output, "--> key\n>> "))
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn lines_until_match() -> Res {
+    let end = regex::Regex::new("^-----END [A-Z ]+-----$")?;
+    let output = test_menu! {
+        menu,
+        "-----BEGIN KEY-----\nabc -----END KEY-----\n-----END KEY-----\nnext\n",
+        let key = Written::from("key").lines_until_match(menu.get_mut_object(), &end)?,
+        assert_eq!(key, "-----BEGIN KEY-----\nabc -----END KEY-----\n-----END KEY-----"),
+    }?;
+
+    Ok(assert_eq!(output, "--> key\n>> "))
+}
+
+#[test]
+fn select_echo_choice() -> Res {
+    let output = test_menu! {
+        menu,
+        "gpl\nmit\n",
+        let name: Type2 = menu.selected(Selected::from("select the type").echo_choice())?,
+        assert_eq!(name, Type2::GPL),
+        let fmt = Format::prefix("* ").with_echo_msg("Choix : "),
+        let name: Type2 = menu.selected(Selected::from("type").format(fmt).echo_choice())?,
+        assert_eq!(name, Type2::MIT),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> select the type\n[1] - MIT\n[2] - GPL\n[3] - BSD\n>> --> Selected: GPL\n\
+        * type\n[1] - MIT\n[2] - GPL\n[3] - BSD\n>> * Choix : MIT\n"
+    ))
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn written_json() -> Res {
+    test_menu! {
+        menu,
+        "example.com\n007\neighty\n8080\n1e5\ny\n",
+        let payload = menu.written_json(&[
+            ("server.host", Written::from("host"), JsonType::String),
+            ("server.code", Written::from("code"), JsonType::String),
+            ("server.port", Written::from("port"), JsonType::Integer),
+            ("ratio", Written::from("ratio"), JsonType::Float),
+            ("verbose", Written::from("verbose").boolean(), JsonType::Bool),
+        ])?,
+        assert_eq!(
+            payload,
+            serde_json::json!({
+                "server": {"host": "example.com", "code": "007", "port": 8080},
+                "ratio": 100000.0,
+                "verbose": true,
+            })
+        ),
+    }?;
+
+    Ok(())
+}
+
+#[test]
+fn form_back_key() -> Res {
+    let output = test_menu! {
+        menu,
+        "Ahmad\n:prev\n\n2\n",
+        menu = menu.record(true),
+        let answers = menu.form(&[
+            FormField::Written(&Written::from("name")),
+            FormField::Selected(&Selected::new("license", [("MIT", 0), ("GPL", 1)])),
+        ])?,
+        assert_eq!(answers, ["Ahmad", "GPL"]),
+        assert_eq!(
+            menu.records(),
+            [("name".to_owned(), "Ahmad".to_owned()), ("license".to_owned(), "GPL".to_owned())]
+        ),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> name\n>> \
+--> license\n[1] - MIT\n[2] - GPL\n>> \
+--> name (default: Ahmad)\n>> \
+--> license\n[1] - MIT\n[2] - GPL\n>> "
+    ))
+}
+
+#[test]
+fn form_records() -> Res {
+    let output = test_menu! {
+        menu,
+        "hunter2\n\n:prev\n:prev\n\n\n\nabc\n3\n",
+        menu = menu.record(true),
+        let answers = menu.form(&[
+            FormField::Written(&Written::from("token").secret_env_with("UNSET", |_| None)),
+            FormField::Written(&Written::from("level").range(1u8..=10).default_value("5")),
+            FormField::Selected(&Selected::new("license", [("MIT", 0), ("GPL", 1)]).default(0)),
+        ])?,
+        assert_eq!(answers, ["hunter2", "5", "MIT"]),
+        assert_eq!(
+            menu.records(),
+            [
+                ("token".to_owned(), "*******".to_owned()),
+                ("level".to_owned(), "5".to_owned()),
+                ("license".to_owned(), "MIT".to_owned()),
+            ]
+        ),
+        assert_eq!(menu.defaults(), ["level", "license"]),
+        // The bounds of a field prompted as a string reject the other inputs.
+        let answers = menu.inline_form(&[Written::from("level").range(1u8..=10)])?,
+        assert_eq!(answers, ["3"]),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> token\n>> \
+--> level (1–10, default: 5)\n>> \
+--> license\n[1] - MIT (default)\n[2] - GPL\n>> \
+--> level (1–10, default: 5)\n>> \
+--> token\n>> \
+--> level (1–10, default: 5)\n>> \
+--> license\n[1] - MIT (default)\n[2] - GPL\n>> \
+level (1–10): level (1–10): "
+    ))
+}
+
+#[test]
+fn form_selected_field() -> Res {
+    let output = test_menu! {
+        menu,
+        "GPL\n:prev\n\n",
+        let license = Selected::new("license", [("MIT", 0), ("GPL", 1)])
+            .group(0, "-- Licenses --"),
+        let answers = menu.form(&[
+            FormField::Selected(&license),
+            FormField::Written(&Written::from("name").default_value("Ahmad")),
+        ])?,
+        assert_eq!(answers, ["GPL", "Ahmad"]),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> license\n-- Licenses --\n[1] - MIT\n[2] - GPL\n>> \
+--> name (default: Ahmad)\n>> \
+--> license\n-- Licenses --\n[1] - MIT\n[2] - GPL (default)\n>> \
+--> name (default: Ahmad)\n>> "
+    ))
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn written_until_async() -> Res {
+    use std::future::Future;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake};
+
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let waker = Arc::new(Noop).into();
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = Box::pin(fut);
+        loop {
+            if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+                return out;
+            }
+        }
+    }
+
+    let output = test_menu! {
+        menu,
+        "admin\nahmad\n",
+        let name: String = block_on(menu.written_until_async(&Written::from("username"), |name: &String| {
+            let taken = name == "admin";
+            async move { !taken }
+        }))?,
+        assert_eq!(name, "ahmad"),
+    }?;
+
+    Ok(assert_eq!(output, "--> username\n>> >> "))
+}
+
+#[test]
+fn form_interrupted() -> Res {
+    test_menu! {
+        menu,
+        "Ahmad\nhunter2\n",
+        let res = menu.form(&[
+            FormField::Written(&Written::from("name")),
+            FormField::Written(&Written::from("token").secret_env_with("UNSET", |_| None)),
+            FormField::Written(&Written::from("age")),
+        ]),
+        match res {
+            Err(MenuError::Interrupted(answers)) => assert_eq!(
+                answers,
+                [
+                    ("name".to_owned(), "Ahmad".to_owned()),
+                    ("token".to_owned(), "*******".to_owned()),
+                ]
+            ),
+            res => panic!("expected an interrupted form, got {:?}", res),
+        },
+    }?;
+
+    Ok(())
+}
+
+#[test]
+fn validate_form() {
+    let menu = Values::default();
+    let name = Written::from("name").default_value("Ahmad");
+    let overwrite = Written::from("overwrite").boolean();
+    let license = Selected::new("license", [("MIT", 0), ("GPL", 1)]);
+
+    assert_eq!(
+        menu.validate(&[
+            FormField::Written(&name),
+            FormField::Written(&overwrite.clone().default_value("yes")),
+            FormField::Selected(&license),
+        ]),
+        Ok(())
+    );
+    assert!(menu
+        .validate(&[
+            FormField::Written(&name),
+            FormField::Written(&overwrite.default_value("maybe")),
+        ])
+        .is_err());
+    assert_eq!(
+        menu.validate(&[FormField::Selected(&license.filter(|n| *n > 1))]),
+        Err(MenuError::EmptyMenu)
+    );
+}
+
+#[test]
+fn inline_form() -> Res {
+    let output = test_menu! {
+        menu,
+        "Ahmad\n19\n",
+        let answers = menu.inline_form(&[Written::from("name"), Written::from("age").default_value("18")])?,
+        assert_eq!(answers, ["Ahmad", "19"]),
+    }?;
+
+    Ok(assert_eq!(output, "name: age (default: 18): "))
+}
+
+#[test]
+fn select_sorted_by() -> Res {
+    let output = test_menu! {
+        menu,
+        "2\n",
+        let license: &str = menu.selected(
+            Selected::new("license", [("MIT", "mit"), ("GPL", "gpl"), ("BSD", "bsd")])
+                .default(1)
+                .sorted_by(|(a, _), (b, _)| a.cmp(b)),
+        )?,
+        assert_eq!(license, "gpl"),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> license\n[1] - BSD\n[2] - GPL (default)\n[3] - MIT\n>> "
+    ))
+}
+
+#[test]
+fn select_matching() -> Res {
+    let output = test_menu! {
+        menu,
+        "g\nxyz\ngpl-3\n\n",
+        let licenses: Vec<&str> = Selected::new(
+            "licenses",
+            [("MIT", "mit"), ("GPL-2.0", "gpl2"), ("GPL-3.0", "gpl3"), ("LGPL", "lgpl")],
+        )
+        .select_matching(menu.get_mut_object())?,
+        assert_eq!(licenses, ["gpl3"]),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> licenses\n[1] - MIT\n[2] - GPL-2.0\n[3] - GPL-3.0\n[4] - LGPL\n\
+        >> - GPL-2.0\n- GPL-3.0\n>> >> - GPL-3.0\n>> "
+    ))
+}
+
+#[test]
+fn select_matching_all() -> Res {
+    test_menu! {
+        menu,
+        "\n",
+        let licenses: Vec<&str> = Selected::new("licenses", [("MIT", "mit"), ("BSD", "bsd")])
+            .select_matching(menu.get_mut_object())?,
+        assert_eq!(licenses, ["mit", "bsd"]),
+    }?;
+    Ok(())
+}
+
+#[test]
+fn written_on_empty() -> Res {
+    test_menu! {
+        menu,
+        "\n\n\n\nhello\n",
+        let skipped: Option<String> = Written::from("nickname")
+            .default_value("anonymous")
+            .on_empty(EmptyBehavior::Skip)
+            .optional_value(menu.get_mut_object())?,
+        assert_eq!(skipped, None),
+        let sentinel: i32 = Written::from("retries")
+            .on_empty(EmptyBehavior::Value("-1"))
+            .prompt(menu.get_mut_object())?,
+        assert_eq!(sentinel, -1),
+        let defaulted: u8 = Written::from("age")
+            .default_value("18")
+            .on_empty(EmptyBehavior::Default)
+            .prompt(menu.get_mut_object())?,
+        assert_eq!(defaulted, 18),
+        let reprompted: Option<String> = Written::from("name")
+            .on_empty(EmptyBehavior::Reprompt)
+            .optional_value(menu.get_mut_object())?,
+        assert_eq!(reprompted.as_deref(), Some("hello")),
+    }?;
+    Ok(())
+}
+
+#[test]
+fn written_on_empty_reprompt_eof() -> Res {
+    test_menu! {
+        menu,
+        "\n",
+        let res: MenuResult<Option<String>> = Written::from("name")
+            .on_empty(EmptyBehavior::Reprompt)
+            .optional_value(menu.get_mut_object()),
+        assert!(matches!(res, Err(MenuError::Eof))),
+    }?;
+    Ok(())
+}
+
+#[test]
+fn run_all_heterogeneous() -> Res {
+    test_menu! {
+        menu,
+        "Ahmad\n2\n18\n",
+        menu = menu.record(true),
+        let fields: Vec<Box<dyn Promptable<_, _>>> = vec![
+            Box::new(Written::from("name")),
+            Box::new(Selected::new("license", [("MIT", 0), ("GPL", 1)])),
+            Box::new(Written::from("age")),
+        ],
+        let answers = menu.run_all(fields)?,
+        assert_eq!(answers, ["Ahmad", "GPL", "18"]),
+        assert_eq!(
+            menu.records(),
+            [
+                ("name".to_owned(), "Ahmad".to_owned()),
+                ("license".to_owned(), "GPL".to_owned()),
+                ("age".to_owned(), "18".to_owned()),
+            ]
+        ),
+    }?;
+    Ok(())
+}
+
+#[test]
+fn decorate_messages() -> Res {
+    let output = test_menu! {
+        menu,
+        "Ahmad\n1\n",
+        menu.fmt = Format::decorate(Some(|msg| format!("[{}]", msg.to_uppercase()))),
+        let _: String = menu.written(&Written::from("name"))?,
+        let _: u8 = menu.selected(Selected::new("license", [("mit", 0), ("gpl", 1)]))?,
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> [NAME]\n>> --> [LICENSE]\n[1] - mit\n[2] - gpl\n>> "
+    ))
+}
+
+#[test]
+fn index_start() -> Res {
+    let output = test_menu! {
+        menu,
+        "3\n1\n",
+        menu.fmt = Format::index_start(0).with_show_range(true),
+        let n: u8 = menu.selected(Selected::new("number", [("zero", 0), ("one", 1), ("two", 2)]))?,
+        assert_eq!(n, 1),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> number\n[0] - zero\n[1] - one\n[2] - two\n[0-2] >> [0-2] >> "
+    ))
+}
+
+#[test]
+fn live_validate_fallback() -> Res {
+    let output = test_menu! {
+        menu,
+        "12a\n",
+        let n: String = Written::from("code")
+            .live_validate(|s| s.chars().all(|c| c.is_ascii_digit()))
+            .prompt(menu.get_mut_object())?,
+        assert_eq!(n, "12a"),
+    }?;
+
+    Ok(assert_eq!(output, "--> code\n>> "))
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn live_validate_redraw() -> Res {
+    let validate: fn(&str) -> bool = |s| s.chars().all(|c| c.is_ascii_digit());
+    let mut output = Vec::<u8>::new();
+    crate::utils::show_validated(&mut output, ">> ", "12", validate)?;
+    crate::utils::show_validated(&mut output, ">> ", "12a", validate)?;
+    Ok(assert_eq!(
+        String::from_utf8(output)?,
+        "\x1b8>> 12\x1b[K\x1b8\x1b[31m>> \x1b[0m12a\x1b[K"
+    ))
+}
+
+#[test]
+fn radix_incorrect_digits() -> Res {
+    let output = test_menu! {
+        menu,
+        "zz\nfe\n1\nzz\n0x2\ndone\n",
+        let a: u8 = menu.written(&Written::from("a").radix(16).default_value("ff"))?,
+        assert_eq!(a, 254),
+        let b: Vec<u8> = menu.many_written_lines(&Written::from("b").radix(16), "done")?,
+        assert_eq!(b, [1, 2]),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> a (default: ff)\n>> >> --> b\n>> >> >> >> "
+    ))
+}
+
+#[test]
+fn radix_multibyte_input() -> Res {
+    test_menu! {
+        menu,
+        "€1\n0€\nff\n",
+        let n: u8 = menu.written(&Written::from("n").radix(16))?,
+        assert_eq!(n, 255),
+    }?;
+    Ok(())
+}
+
+#[test]
+fn written_on_empty_recorded() -> Res {
+    let output = test_menu! {
+        menu,
+        "\n\n\n1, 2\n",
+        menu = menu.record(true),
+        let nick: Option<String> = menu.optional_written(
+            &Written::from("nick").default_value("anon").on_empty(EmptyBehavior::Skip),
+        )?,
+        assert_eq!(nick, None),
+        let retries: i32 = menu.written(&Written::from("retries").on_empty(EmptyBehavior::Value("-1")))?,
+        assert_eq!(retries, -1),
+        let ids: Vec<u8> = menu.many_written(&Written::from("ids").on_empty(EmptyBehavior::Reprompt), ", ")?,
+        assert_eq!(ids, [1, 2]),
+        assert_eq!(
+            menu.records(),
+            [
+                ("nick".to_owned(), String::new()),
+                ("retries".to_owned(), "-1".to_owned()),
+                ("ids".to_owned(), "1, 2".to_owned()),
+            ]
+        ),
+        assert_eq!(menu.defaults(), ["retries"]),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> nick (default: anon)\n>> --> retries\n>> --> ids\n>> >> "
+    ))
+}
+
+#[test]
+fn owned_messages() -> Res {
+    let remaining = 3;
+    let output = test_menu! {
+        menu,
+        "Ahmad\n1\n5\n",
+        let name: String = menu.written(&Written::from(format!("name ({} remaining)", remaining)))?,
+        assert_eq!(name, "Ahmad"),
+        let n: u8 = menu.selected(Selected::new(format!("item {}", remaining), [("one", 1)]))?,
+        assert_eq!(n, 1),
+        let day: u8 = menu.selected_range(SelectedRange::new(String::from("day"), 1..=7))?,
+        assert_eq!(day, 5),
+    }?;
+
+    Ok(assert_eq!(
+        output,
+        "--> name (3 remaining)\n>> --> item 3\n[1] - one\n>> --> day (1–7)\n>> "
+    ))
+}
@@ -0,0 +1,69 @@
+use crate::MenuError;
+use std::env::VarError;
+use std::error::Error;
+use std::{fmt, io};
+
+#[test]
+fn error_constructors() {
+    let err = MenuError::other(vec![4, 2]);
+    assert_eq!(err, MenuError::Other(Box::new(())));
+    assert_eq!(err.to_string(), "[4, 2]");
+
+    let err = MenuError::env_var("HOME", VarError::NotPresent);
+    assert!(matches!(err, MenuError::EnvVar(ref v, VarError::NotPresent) if v == "HOME"));
+    assert_eq!(
+        err.to_string(),
+        "attempted to get a default value from the environment variable `HOME`: \
+environment variable not found"
+    );
+}
+
+#[test]
+fn error_conversions() {
+    let err = MenuError::from(io::Error::new(io::ErrorKind::BrokenPipe, "broken pipe"));
+    assert_eq!(err, MenuError::IOError(io::ErrorKind::BrokenPipe.into()));
+    assert_eq!(err.to_string(), "IO error: broken pipe");
+
+    let err = MenuError::from("no such user");
+    assert_eq!(err, MenuError::Other(Box::new(())));
+    assert_eq!(err.to_string(), "\"no such user\"");
+
+    let err = MenuError::from(String::from("no such user"));
+    assert_eq!(err.to_string(), "\"no such user\"");
+
+    let err = MenuError::from(fmt::Error);
+    assert_eq!(err, MenuError::Format(fmt::Error));
+    assert_eq!(
+        err.to_string(),
+        "an error occurred while formatting a field: Error"
+    );
+
+    let boxed: Box<dyn Error> = "failure".into();
+    let err = MenuError::from(boxed);
+    assert_eq!(err, MenuError::Other(Box::new(())));
+    assert_eq!(err.to_string(), "\"failure\"");
+}
+
+#[test]
+fn error_from_parse() {
+    let errors = [
+        MenuError::from("a".parse::<u8>().unwrap_err()),
+        MenuError::from("a".parse::<f32>().unwrap_err()),
+        MenuError::from("a".parse::<bool>().unwrap_err()),
+        MenuError::from("ab".parse::<char>().unwrap_err()),
+    ];
+    for err in errors {
+        assert_eq!(err, MenuError::Input);
+        assert_eq!(err.to_string(), "an incorrect input has been provided");
+    }
+}
+
+#[test]
+fn error_question_mark() {
+    fn parse(s: &str) -> Result<u8, MenuError> {
+        Ok(s.parse::<u8>()?)
+    }
+
+    assert_eq!(parse("8"), Ok(8));
+    assert_eq!(parse("eight"), Err(MenuError::Input));
+}
@@ -5,7 +5,8 @@ use crate::prelude::*;
 use std::any::type_name;
 use std::fmt::Display;
 use std::io::BufRead;
-use std::io::Write;
+use std::io::{IsTerminal, Write};
+use std::time::{Duration, Instant};
 
 /// Type to handle the depth of the running menus.
 pub(crate) enum Depth {
@@ -27,55 +28,746 @@ pub(crate) fn keep<T>(_val: &T) -> bool {
 }
 
 /// Shows the text using the given stream and maps the `io::Error` into a `MenuError`.
+///
+/// Unlike the prompts, which are flushed once right before reading the user input,
+/// the text is flushed immediately.
 pub(crate) fn show<T: ?Sized + Display, S: Write>(text: &T, stream: &mut S) -> MenuResult {
     write!(stream, "{}", text)?;
     stream.flush().map_err(MenuError::from)
 }
 
-/// Shows the text using the given stream, then prompts a value to the user and
-/// returns the corresponding String.
-pub(crate) fn prompt<T: ?Sized + Display, R: BufRead, W: Write>(
-    text: &T,
+/// Returns the number of columns used to display the given string slice in a terminal.
+///
+/// With the `unicode-width` feature, the wide characters, such as the CJK characters
+/// or the emojis, are counted as two columns. Otherwise, each character is counted
+/// as one column.
+pub(crate) fn display_width(s: &str) -> usize {
+    #[cfg(feature = "unicode-width")]
+    {
+        unicode_width::UnicodeWidthStr::width(s)
+    }
+    #[cfg(not(feature = "unicode-width"))]
+    {
+        s.chars().count()
+    }
+}
+
+/// Shows the message of the format used when the user input is incorrect,
+/// if it is not empty.
+pub(crate) fn show_invalid<S: Write>(fmt: &Format<'_>, stream: &mut S) -> MenuResult {
+    if fmt.quiet || fmt.invalid_msg.is_empty() {
+        return Ok(());
+    }
+    write!(stream, "{}{}", fmt.invalid_msg, fmt.line_ending).map_err(MenuError::from)
+}
+
+/// Shows the message of the format used when the user input is incorrect, followed by
+/// the given errors, one per line after the chip of the format.
+pub(crate) fn show_invalid_errors<S: Write>(
+    fmt: &Format<'_>,
+    stream: &mut S,
+    errors: &[String],
+) -> MenuResult {
+    show_invalid(fmt, stream)?;
+    if fmt.quiet || fmt.invalid_msg.is_empty() {
+        return Ok(());
+    }
+    for e in errors {
+        write!(stream, "{}{}{}", fmt.chip, e, fmt.line_ending)?;
+    }
+    Ok(())
+}
+
+/// Calls the given function until it does not fail with an IO error,
+/// at most `retries` more times, and returns its last output.
+///
+/// The errors of the writes done through [`std::fmt::Write`] are also retried,
+/// because they are converted to [`MenuError::Format`] errors.
+pub(crate) fn retry_io<T, F: FnMut() -> MenuResult<T>>(retries: usize, mut f: F) -> MenuResult<T> {
+    let mut left = retries;
+    loop {
+        match f() {
+            Err(MenuError::IOError(_) | MenuError::Format(_)) if left > 0 => left -= 1,
+            res => return res,
+        }
+    }
+}
+
+/// Shows the suffix of the format using the given stream, then prompts a value to the user
+/// and returns the corresponding String.
+///
+/// If the format asks for it, it writes a line break after reading the input.
+pub(crate) fn prompt<R: BufRead, W: Write>(
+    fmt: &Format<'_>,
     stream: &mut MenuStream<R, W>,
 ) -> MenuResult<String> {
-    show(text, stream)?;
-    read_input(stream)
+    if fmt.quiet {
+        return read_input(stream);
+    }
+    write!(stream, "{}", fmt.suffix)?;
+    let out = read_input(stream)?;
+    if fmt.echo_newline {
+        write!(stream, "{}", fmt.line_ending)?;
+    }
+    Ok(out)
 }
 
-/// Panics at runtime, emphasizing that the given `default` value is incorrect for `T` type.
-pub(crate) fn default_failed<T>(default: &str) -> ! {
-    panic!(
+/// Shows the suffix of the format using the given stream, then prompts a value to the user
+/// with the input pre-filled with the given text, and returns the corresponding String.
+///
+/// The input is pre-filled only with the `crossterm` feature, and if the standard input
+/// is a terminal, by reading the key events in raw mode. Otherwise, it prompts the value
+/// like the [`prompt`] function.
+pub(crate) fn prompt_prefilled<R: BufRead, W: Write>(
+    fmt: &Format<'_>,
+    stream: &mut MenuStream<R, W>,
+    text: &str,
+) -> MenuResult<String> {
+    #[cfg(feature = "crossterm")]
+    {
+        use crossterm::{terminal, tty::IsTty};
+
+        if !fmt.quiet && std::io::stdin().is_tty() {
+            show(fmt.suffix, stream)?;
+            show(text, stream)?;
+
+            let mut out = text.to_owned();
+            terminal::enable_raw_mode()?;
+            let res = edit_line(stream, &mut out, None, None);
+            terminal::disable_raw_mode()?;
+            res?;
+
+            show(fmt.line_ending.as_str(), stream)?;
+            if fmt.echo_newline {
+                show(fmt.line_ending.as_str(), stream)?;
+            }
+            return Ok(stream.preprocessed(out.trim().to_owned()));
+        }
+    }
+
+    let _ = text;
+    prompt(fmt, stream)
+}
+
+/// Shows the suffix of the format using the given stream, then prompts a value to the user
+/// without echoing it, and returns the corresponding String.
+///
+/// The input is masked only with the `crossterm` feature, and if the standard input
+/// is a terminal, by reading the key events in raw mode. Otherwise, it prompts the value
+/// like the [`prompt`] function.
+pub(crate) fn prompt_masked<R: BufRead, W: Write>(
+    fmt: &Format<'_>,
+    stream: &mut MenuStream<R, W>,
+) -> MenuResult<String> {
+    #[cfg(feature = "crossterm")]
+    {
+        use crossterm::{terminal, tty::IsTty};
+
+        if !fmt.quiet && std::io::stdin().is_tty() {
+            show(fmt.suffix, stream)?;
+
+            let mut out = String::new();
+            terminal::enable_raw_mode()?;
+            let res = edit_line(stream, &mut out, Some('*'), None);
+            terminal::disable_raw_mode()?;
+            res?;
+
+            show(fmt.line_ending.as_str(), stream)?;
+            if fmt.echo_newline {
+                show(fmt.line_ending.as_str(), stream)?;
+            }
+            let out = stream.preprocessed(out.trim().to_owned());
+            return Ok(masked_input(stream, out));
+        }
+    }
+
+    let out = prompt(fmt, stream)?;
+    Ok(masked_input(stream, out))
+}
+
+/// Replaces the last input of the stream by the mask of the given secret input,
+/// so the secret does not remain in the stream, and returns the secret input.
+fn masked_input<R, W>(stream: &mut MenuStream<R, W>, input: String) -> String {
+    stream.set_last_input(&"*".repeat(input.chars().count().min(8)));
+    input
+}
+
+/// Function validating the user input while the user types it (see [`prompt_validated`]).
+type Validate = fn(&str) -> bool;
+
+/// Shows the suffix of the format using the given stream, then prompts a value to the user
+/// while validating it on each keystroke, and returns the corresponding String.
+///
+/// The suffix is displayed in red as long as the input is rejected by `validate`.
+/// The input is validated on each keystroke only with the `crossterm` feature, and if the
+/// standard input is a terminal, by reading the key events in raw mode. Otherwise,
+/// it prompts the value like the [`prompt`] function.
+pub(crate) fn prompt_validated<R: BufRead, W: Write>(
+    fmt: &Format<'_>,
+    stream: &mut MenuStream<R, W>,
+    validate: Validate,
+) -> MenuResult<String> {
+    #[cfg(feature = "crossterm")]
+    {
+        use crossterm::{terminal, tty::IsTty};
+
+        if !fmt.quiet && std::io::stdin().is_tty() {
+            // Saves the cursor position, so the suffix and the input are redrawn from it.
+            show("\x1b7", stream)?;
+            show_validated(stream, fmt.suffix, "", validate)?;
+
+            let mut out = String::new();
+            terminal::enable_raw_mode()?;
+            let res = edit_line(stream, &mut out, None, Some((fmt.suffix, validate)));
+            terminal::disable_raw_mode()?;
+            res?;
+
+            show(fmt.line_ending.as_str(), stream)?;
+            if fmt.echo_newline {
+                show(fmt.line_ending.as_str(), stream)?;
+            }
+            return Ok(stream.preprocessed(out.trim().to_owned()));
+        }
+    }
+
+    let _ = validate;
+    prompt(fmt, stream)
+}
+
+/// Redraws the given suffix and input from the saved cursor position,
+/// with the suffix in red if the input is rejected by `validate`.
+#[cfg(feature = "crossterm")]
+pub(crate) fn show_validated<W: Write>(
+    stream: &mut W,
+    suffix: &str,
+    input: &str,
+    validate: Validate,
+) -> MenuResult {
+    match validate(input) {
+        true => show(&format_args!("\x1b8{}{}\x1b[K", suffix, input), stream),
+        false => show(
+            &format_args!("\x1b8\x1b[31m{}\x1b[0m{}\x1b[K", suffix, input),
+            stream,
+        ),
+    }
+}
+
+/// Edits the given line with the key events read in raw mode, until the user presses Enter.
+///
+/// If a mask is given, it is displayed instead of each character of the line.
+/// If a suffix and a validation function are given, the suffix and the line are redrawn
+/// on each keystroke instead (see [`prompt_validated`]).
+/// Pressing Ctrl-C or Ctrl-D results in a [`MenuError::Eof`] error.
+#[cfg(feature = "crossterm")]
+fn edit_line<W: Write>(
+    stream: &mut W,
+    out: &mut String,
+    mask: Option<char>,
+    live: Option<(&str, Validate)>,
+) -> MenuResult {
+    use crossterm::event::{read, Event, KeyCode, KeyEvent, KeyModifiers};
+
+    loop {
+        if let Event::Key(KeyEvent { code, modifiers }) = read()? {
+            match code {
+                KeyCode::Enter => return Ok(()),
+                KeyCode::Char('c' | 'd') if modifiers == KeyModifiers::CONTROL => {
+                    return Err(MenuError::Eof)
+                }
+                KeyCode::Backspace => match out.pop() {
+                    Some(c) if live.is_none() => {
+                        let width = display_width(mask.unwrap_or(c).encode_utf8(&mut [0; 4]));
+                        show(&"\x08 \x08".repeat(width), stream)?;
+                    }
+                    _ => (),
+                },
+                KeyCode::Char(c) => {
+                    out.push(c);
+                    if live.is_none() {
+                        show(&mask.unwrap_or(c), stream)?;
+                    }
+                }
+                _ => continue,
+            }
+            if let Some((suffix, validate)) = live {
+                show_validated(stream, suffix, out, validate)?;
+            }
+        }
+    }
+}
+
+/// Displays a countdown in place, and returns `true` if it elapsed without any keypress
+/// of the user.
+///
+/// The countdown runs only with the `crossterm` feature, and if the standard input
+/// is a terminal, by detecting the keypresses in raw mode. Otherwise, it returns `false`
+/// immediately.
+pub(crate) fn countdown<W: Write>(
+    stream: &mut W,
+    fmt: &Format<'_>,
+    duration: Duration,
+) -> MenuResult<bool> {
+    #[cfg(feature = "crossterm")]
+    {
+        use crossterm::{terminal, tty::IsTty};
+
+        if std::io::stdin().is_tty() {
+            terminal::enable_raw_mode()?;
+            let res = wait_keypress(stream, fmt, duration);
+            terminal::disable_raw_mode()?;
+            let elapsed = res?;
+
+            // Clears the line of the countdown.
+            show("\r\x1b[2K", stream)?;
+            return Ok(elapsed);
+        }
+    }
+
+    let _ = (stream, fmt, duration);
+    Ok(false)
+}
+
+/// Displays the remaining seconds of the countdown each second, until a key is pressed,
+/// and returns `true` if the countdown elapsed.
+#[cfg(feature = "crossterm")]
+fn wait_keypress<W: Write>(
+    stream: &mut W,
+    fmt: &Format<'_>,
+    duration: Duration,
+) -> MenuResult<bool> {
+    use crossterm::event::{poll, read, Event};
+
+    let start = Instant::now();
+    loop {
+        let remaining = match duration.checked_sub(start.elapsed()) {
+            Some(r) if !r.is_zero() => r,
+            _ => return Ok(true),
+        };
+        let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+        show(
+            &format_args!(
+                "\r{}Selecting the default value in {}s...",
+                fmt.prefix, secs
+            ),
+            stream,
+        )?;
+
+        if poll(remaining - Duration::from_secs(secs - 1))? {
+            if let Event::Key(_) = read()? {
+                return Ok(false);
+            }
+        }
+    }
+}
+
+/// Returns the displayed index of the field at the given position, starting from 1,
+/// padded according to the format to the width of the largest index.
+///
+/// See the `index_start` and `index_pad` specifications of [`Format`] for more information.
+pub(crate) fn padded_index(fmt: &Format<'_>, i: usize, count: usize) -> String {
+    let i = (i - 1 + fmt.index_start).to_string();
+    match fmt.index_pad {
+        Some(c) => {
+            let width = (count - 1 + fmt.index_start).to_string().len();
+            let pad = c.to_string().repeat(width.saturating_sub(i.len()));
+            pad + &i
+        }
+        None => i,
+    }
+}
+
+/// Parses a boolean value, accepting `true`/`false`, `y`/`n`, `yes`/`no`, `1`/`0`
+/// and `on`/`off`, case-insensitively.
+pub(crate) fn parse_bool(s: &str) -> Option<bool> {
+    match s.trim().to_ascii_lowercase().as_str() {
+        "true" | "y" | "yes" | "1" | "on" => Some(true),
+        "false" | "n" | "no" | "0" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// Parses an integer written in the given radix, and returns it written in decimal,
+/// or `None` if it contains incorrect digits.
+///
+/// The integer may start with a sign, and with the `0x`, `0o` or `0b` prefix
+/// corresponding to the radix.
+pub(crate) fn parse_radix(s: &str, radix: u32) -> Option<String> {
+    let s = s.trim();
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => ("-", s),
+        None => ("", s.strip_prefix('+').unwrap_or(s)),
+    };
+    let prefix = match radix {
+        16 => Some("0x"),
+        8 => Some("0o"),
+        2 => Some("0b"),
+        _ => None,
+    };
+    let s = match prefix {
+        // The prefix is checked before slicing, because the input may start
+        // with a multibyte character.
+        Some(p) if s.len() > 2 && s.get(..2).is_some_and(|h| h.eq_ignore_ascii_case(p)) => &s[2..],
+        _ => s,
+    };
+    // The sign has been stripped.
+    if s.starts_with(['+', '-']) {
+        return None;
+    }
+
+    u128::from_str_radix(s, radix)
+        .ok()
+        .map(|n| format!("{}{}", sign, n))
+}
+
+/// Splits the input into values with the given separator.
+///
+/// If `keep_empty` is `false`, the values are trimmed, and the empty ones are skipped.
+pub(crate) fn split_values<'s>(
+    s: &'s str,
+    sep: &'s str,
+    keep_empty: bool,
+) -> impl Iterator<Item = &'s str> {
+    s.split(sep)
+        .map(move |v| if keep_empty { v } else { v.trim() })
+        .filter(move |v| keep_empty || !v.is_empty())
+}
+
+/// Parses a number followed by an optional unit, and returns the number multiplied
+/// by the factor of the unit.
+///
+/// The longest unit matching the end of the input is used.
+pub(crate) fn parse_unit(s: &str, units: &[(&str, u64)]) -> Option<u64> {
+    let s = s.trim();
+    let (num, factor) = units
+        .iter()
+        .filter(|(unit, _)| !unit.is_empty() && s.ends_with(unit))
+        .max_by_key(|(unit, _)| unit.len())
+        .map_or((s, 1), |(unit, factor)| {
+            (&s[..s.len() - unit.len()], *factor)
+        });
+
+    num.trim_end().parse::<u64>().ok()?.checked_mul(factor)
+}
+
+/// Parses a duration written with units, such as `"1h30m"`, `"90s"` or `"2d"`.
+///
+/// The duration is a sequence of numbers each followed by a unit among `ms`, `s`, `m`,
+/// `h` and `d`, matched case-sensitively. The whitespaces around the numbers and the units
+/// are ignored, and the durations of all the numbers are added.
+pub(crate) fn parse_duration(s: &str) -> Option<Duration> {
+    let mut rest = s.trim();
+    if rest.is_empty() {
+        return None;
+    }
+
+    let mut total = Duration::ZERO;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let n: u64 = rest[..digits].parse().ok()?;
+        let tail = rest[digits..].trim_start();
+        let letters = tail
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(tail.len());
+        let secs = |factor: u64| n.checked_mul(factor).map(Duration::from_secs);
+        let d = match &tail[..letters] {
+            "ms" => Some(Duration::from_millis(n)),
+            "s" => secs(1),
+            "m" => secs(60),
+            "h" => secs(60 * 60),
+            "d" => secs(24 * 60 * 60),
+            _ => None,
+        }?;
+        total = total.checked_add(d)?;
+        rest = tail[letters..].trim_start();
+    }
+
+    Some(total)
+}
+
+/// Returns the message emphasizing that the given `default` value is incorrect for `T` type.
+pub(crate) fn default_failed_msg<T>(default: &str) -> String {
+    format!(
         "`{}` has been used as default value but is incorrect for `{}` type",
         default,
         type_name::<T>(),
     )
 }
 
+/// Panics at runtime, emphasizing that the given `default` value is incorrect for `T` type.
+pub(crate) fn default_failed<T>(default: &str) -> ! {
+    panic!("{}", default_failed_msg::<T>(default))
+}
+
 /// Returns the input value as a String from the given input stream.
-pub(crate) fn read_input<R: BufRead, W>(stream: &mut MenuStream<R, W>) -> MenuResult<String> {
-    let mut out = String::new();
-    stream.read_line(&mut out)?;
-    Ok(out.trim().to_owned())
+///
+/// The output of the stream is flushed before reading the input, so the whole prompt
+/// is displayed at once.
+///
+/// If the input stream reached its end, it returns a [`MenuError::Eof`] error.
+/// The pending empty lines are skipped if the stream drains them
+/// (see [`MenuStream::drain_pending`]), then the line is preprocessed by the stream
+/// (see [`MenuStream::on_read`]).
+pub(crate) fn read_input<R: BufRead, W: Write>(
+    stream: &mut MenuStream<R, W>,
+) -> MenuResult<String> {
+    stream.flush()?;
+    loop {
+        let mut out = String::new();
+        if stream.read_line(&mut out)? == 0 {
+            return Err(MenuError::Eof);
+        }
+        let out = out.trim();
+        if !stream.drained(out.is_empty()) {
+            let out = stream.preprocessed(out.to_owned());
+            stream.set_last_input(&out);
+            return Ok(out);
+        }
+    }
 }
 
-/// Prompts the user to enter an index to select a value among the available values.
+/// Prompts the user to select a value among the available values.
 ///
 /// The available values are in theory printed before calling this function.
+/// The input is matched against the indexes and the labels of the values
+/// according to the [match mode](MatchMode) of the given format.
+///
+/// If the input is incorrect, or if the input stream reached its end, it returns
+/// the default index if it is provided and in bounds. Otherwise, the end of the input
+/// stream results in a [`MenuError::Eof`] error.
+///
+/// If the details of the values are provided, an input prefixed with `'?'` displays
+/// the detail of the matching value, then the user is prompted again.
+///
+/// If the mnemonics of the values are provided, an input made of a single letter
+/// selects the value with the matching mnemonic, before any other match.
 pub(crate) fn select<R: BufRead, W: Write>(
     stream: &mut MenuStream<R, W>,
-    suffix: &str,
-    max: usize,
+    fmt: &Format<'_>,
+    labels: &[&str],
+    details: &[&str],
+    mnemonics: &[Option<char>],
+    default: Option<usize>,
 ) -> MenuResult<Option<usize>> {
-    let s = prompt(suffix, stream)?;
-    Ok(match s.parse::<usize>() {
-        Ok(i) if i >= 1 && i <= max => Some(i - 1),
+    let default = default.filter(|i| *i < labels.len());
+    loop {
+        if fmt.show_range && fmt.show_index && !fmt.quiet && !labels.is_empty() {
+            write!(
+                stream,
+                "{}{}-{}{} ",
+                fmt.left_sur,
+                fmt.index_start,
+                labels.len() - 1 + fmt.index_start,
+                fmt.right_sur
+            )?;
+        }
+        let s = match prompt(fmt, stream) {
+            Err(MenuError::Eof) if default.is_some() => return Ok(default),
+            res => res?,
+        };
+
+        let detail = s
+            .strip_prefix('?')
+            .and_then(|s| parse_selection(s, labels, fmt))
+            .and_then(|i| details.get(i));
+        match detail {
+            Some(detail) if !fmt.quiet => {
+                write!(stream, "{}{}{}", fmt.prefix, detail, fmt.line_ending)?
+            }
+            Some(_) => (),
+            None => {
+                return Ok(parse_mnemonic(&s, mnemonics)
+                    .or_else(|| parse_selection(&s, labels, fmt))
+                    .or(default))
+            }
+        }
+    }
+}
+
+/// Returns the height of the terminal in lines, if it can be detected.
+///
+/// It is detected only with the `crossterm` feature, and if the standard output
+/// is a terminal.
+pub(crate) fn terminal_height() -> Option<usize> {
+    #[cfg(feature = "crossterm")]
+    {
+        use crossterm::{terminal, tty::IsTty};
+
+        if std::io::stdout().is_tty() {
+            return terminal::size().ok().map(|(_, h)| h as usize);
+        }
+    }
+
+    None
+}
+
+/// Returns the match mode used to select a value with the given format.
+///
+/// If the indexes are hidden (see the `show_index` specification of [`Format`]),
+/// the values are only matched by their labels.
+pub(crate) fn match_mode(fmt: &Format<'_>) -> MatchMode {
+    match fmt.show_index {
+        true => fmt.match_mode,
+        false => MatchMode::LabelOnly,
+    }
+}
+
+/// Returns the index of the value matching the given input, according to the match mode
+/// of the given format, and the index of its first value.
+///
+/// See [`MatchMode`] for more information about how the input is matched.
+pub(crate) fn parse_selection(s: &str, labels: &[&str], fmt: &Format<'_>) -> Option<usize> {
+    let s = s.trim();
+    let by_index = || match s.parse::<usize>() {
+        Ok(i) if i >= fmt.index_start => Some(i - fmt.index_start).filter(|i| *i < labels.len()),
         _ => None,
-    })
+    };
+    let by_label = || labels.iter().position(|l| l.trim().eq_ignore_ascii_case(s));
+
+    match match_mode(fmt) {
+        MatchMode::IndexFirst => by_index().or_else(by_label),
+        MatchMode::LabelFirst => by_label().or_else(by_index),
+        MatchMode::IndexOnly => by_index(),
+        MatchMode::LabelOnly => by_label(),
+    }
+}
+
+/// Returns the index of the value whose mnemonic matches the given input,
+/// if the input is made of a single letter.
+fn parse_mnemonic(s: &str, mnemonics: &[Option<char>]) -> Option<usize> {
+    let mut chars = s.trim().chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => {
+            let c = c.to_lowercase().next();
+            mnemonics.iter().position(|m| m.is_some() && *m == c)
+        }
+        _ => None,
+    }
+}
+
+/// Checks that the menu fields are not empty at runtime, before prompting them.
+///
+/// Otherwise, the user would be prompted forever, because no input is correct.
+pub(crate) fn check_fields<T>(fields: &[T]) -> MenuResult {
+    match fields.is_empty() {
+        true => Err(MenuError::EmptyMenu),
+        false => Ok(()),
+    }
+}
+
+/// The frames of the spinner animation.
+const SPINNER_FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+
+/// The minimum delay between two frames of the spinner animation.
+const SPINNER_DELAY: Duration = Duration::from_millis(100);
+
+/// Animated indicator written to a stream, showing that a slow work is running.
+///
+/// It is useful for the mapped functions of a menu doing slow operations,
+/// so the terminal does not look frozen. The indicator is displayed on the current line,
+/// with a carriage return, and is cleared when the spinner is dropped, so the next prompts
+/// are displayed normally.
+///
+/// The spinner does not spawn any thread: you have to call [`Spinner::tick`] regularly
+/// during the work, which draws the next frame when it is time to.
+///
+/// The spinner is only drawn if the standard output is a terminal, so it does not fill
+/// a log file or a pipe, and if the format is not quiet (see [`Spinner::with_format`]).
+/// Otherwise, it does nothing.
+///
+/// # Example
+///
+/// ```no_run
+/// use ezmenulib::prelude::*;
+///
+/// fn download(s: &mut MenuStream) -> MenuResult {
+///     let mut spinner = Spinner::new(s, "Downloading...")?;
+///     for _chunk in 0..1000 {
+///         // ...
+///         spinner.tick()?;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct Spinner<'s, 'a, R, W: Write> {
+    stream: &'s mut MenuStream<'a, R, W>,
+    msg: &'s str,
+    frame: usize,
+    last: Instant,
+    active: bool,
+}
+
+impl<'s, 'a, R, W: Write> Spinner<'s, 'a, R, W> {
+    /// Displays the first frame of the spinner with the given message, to the given stream.
+    pub fn new(stream: &'s mut MenuStream<'a, R, W>, msg: &'s str) -> MenuResult<Self> {
+        Self::with_format(stream, msg, &Format::default())
+    }
+
+    /// Displays the first frame of the spinner with the given message, to the given stream,
+    /// unless the given format is quiet.
+    pub fn with_format(
+        stream: &'s mut MenuStream<'a, R, W>,
+        msg: &'s str,
+        fmt: &Format<'_>,
+    ) -> MenuResult<Self> {
+        let active = !fmt.quiet && std::io::stdout().is_terminal();
+        Self::inner_new(stream, msg, active)
+    }
+
+    /// Returns the spinner, displaying its first frame if it is active.
+    pub(crate) fn inner_new(
+        stream: &'s mut MenuStream<'a, R, W>,
+        msg: &'s str,
+        active: bool,
+    ) -> MenuResult<Self> {
+        let mut spinner = Self {
+            stream,
+            msg,
+            frame: 0,
+            last: Instant::now(),
+            active,
+        };
+        spinner.draw()?;
+        Ok(spinner)
+    }
+
+    fn draw(&mut self) -> MenuResult {
+        if !self.active {
+            return Ok(());
+        }
+        let frame = SPINNER_FRAMES[self.frame % SPINNER_FRAMES.len()];
+        show(&format_args!("\r{} {}", frame, self.msg), self.stream)
+    }
+
+    /// Draws the next frame of the spinner, if the previous one has been displayed
+    /// long enough.
+    pub fn tick(&mut self) -> MenuResult {
+        if self.last.elapsed() < SPINNER_DELAY {
+            return Ok(());
+        }
+        self.frame += 1;
+        self.last = Instant::now();
+        self.draw()
+    }
+}
+
+impl<R, W: Write> std::fmt::Debug for Spinner<'_, '_, R, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Spinner")
+            .field("msg", &self.msg)
+            .field("frame", &self.frame)
+            .finish()
+    }
 }
 
-/// Checks that the menu fields are not empty at runtime.
-pub(crate) fn check_fields<T>(fields: &[T]) {
-    if fields.is_empty() {
-        panic!("empty fields for the selectable values");
+impl<R, W: Write> Drop for Spinner<'_, '_, R, W> {
+    fn drop(&mut self) {
+        if !self.active {
+            return;
+        }
+        // Clears the line of the spinner.
+        let blank = " ".repeat(display_width(self.msg) + 2);
+        let _ = show(&format_args!("\r{}\r", blank), self.stream);
     }
 }